@@ -14,7 +14,7 @@ pub enum MultisigError {
     OwnersCannotBeEmpty: (),
     /// Threshold cannot be zero
     ThresholdCannotBeZero: (),
-    /// Threshold cannot be greater than the number of owners
+    /// Threshold cannot be greater than the total weight of the owners
     ThresholdCannotBeGreaterThanOwners: (),
     /// The threshold has not been reached
     ThresholdNotReached: (),
@@ -40,4 +40,52 @@ pub enum MultisigError {
     TransactionExpired: (),
     /// Maximum number of transactions reached
     MaxTransactionsReached: (),
+    /// A batch transaction must contain at least one operation
+    EmptyBatch: (),
+    /// The signer of a signature could not be recovered
+    InvalidSignature: (),
+    /// The weight of an owner cannot be zero
+    WeightCannotBeZero: (),
+    /// The account does not have the proposer role
+    NotProposer: (),
+    /// The account does not have the approver role
+    NotApprover: (),
+    /// The account does not have the rejecter role
+    NotRejecter: (),
+    /// The account does not have the executor role
+    NotExecutor: (),
+    /// The amount exceeds the remaining allowance
+    AllowanceExceeded: (),
+    /// The execution delay since the threshold was reached has not passed yet
+    TimelockNotExpired: (),
+    /// The account has not voted
+    NotVoted: (),
+    /// Only the proposer of the transaction can cancel it
+    NotTransactionProposer: (),
+    /// The owners or the threshold changed after the transaction was proposed
+    ConfigEpochChanged: (),
+    /// The module is already enabled
+    ModuleAlreadyEnabled: (),
+    /// The module is not enabled
+    ModuleNotEnabled: (),
+    /// The guard blocked the execution of the transaction
+    TransactionBlockedByGuard: (),
+    /// The account is not a guardian
+    NotGuardian: (),
+    /// Duplicate guardian
+    DuplicatedGuardian: (),
+    /// The guardian threshold must be between 1 and the number of guardians, or 0 without guardians
+    InvalidGuardianThreshold: (),
+    /// There is already a recovery in progress
+    RecoveryAlreadyInProgress: (),
+    /// There is no recovery in progress
+    NoRecoveryInProgress: (),
+    /// The recovery has not been approved by the guardians or its delay has not passed yet
+    RecoveryDelayNotExpired: (),
+    /// The inactivity period cannot be zero when a beneficiary is set
+    InvalidInactivityPeriod: (),
+    /// No beneficiary has been set for the inactivity switch
+    InactivitySwitchNotSet: (),
+    /// An owner has been active during the inactivity period
+    OwnersStillActive: (),
 }
@@ -1,59 +1,234 @@
 library;
 
+use std::asset_id::AssetId;
 use ::types::*;
 
 /// Event emitted when the constructor is called
 pub struct MultisigInitialized{
     pub contract_id: ContractId,
-    pub threshold: u8,
-    pub owners: Vec<Identity>
+    pub threshold: Weight,
+    pub owners: Vec<Owner>
 }
 
 /// Event emitted when the threshold is changed
 pub struct ThresholdChanged{
-    pub new_threshold: u8
+    pub new_threshold: Weight,
+    pub config_epoch: u64
+}
+
+/// Event emitted when the execution delay is changed
+pub struct ExecutionDelayChanged{
+    pub new_delay: u64
+}
+
+/// Event emitted when the executor policy is changed
+pub struct ExecutorPolicyChanged{
+    pub new_policy: ExecutorPolicy
+}
+
+/// Event emitted when the guard is changed
+pub struct GuardChanged{
+    pub guard: Option<ContractId>
 }
 
 /// Event emitted when an owner is added
 pub struct OwnerAdded{
-    pub owner: Identity
+    pub owner: Identity,
+    pub weight: Weight,
+    pub config_epoch: u64
+}
+
+/// Event emitted when the weight of an owner is changed
+pub struct OwnerWeightChanged{
+    pub owner: Identity,
+    pub weight: Weight,
+    pub config_epoch: u64
 }
 
 /// Event emitted when an owner is removed
 pub struct OwnerRemoved{
-    pub owner: Identity
+    pub owner: Identity,
+    pub config_epoch: u64
+}
+
+/// Event emitted when an owner is replaced by a new one
+pub struct OwnerSwapped{
+    pub old_owner: Identity,
+    pub new_owner: Identity,
+    pub weight: Weight,
+    pub config_epoch: u64
+}
+
+/// Event emitted when the roles of an identity are changed
+pub struct RolesChanged{
+    pub identity: Identity,
+    pub roles: Roles
+}
+
+/// Event emitted when an allowance is set
+pub struct AllowanceSet{
+    pub delegate: Identity,
+    pub asset_id: AssetId,
+    pub amount: u64,
+    pub reset_period: u64
+}
+
+/// Event emitted when an allowance is spent
+pub struct AllowanceSpent{
+    pub delegate: Identity,
+    pub asset_id: AssetId,
+    pub to: Identity,
+    pub amount: u64,
+    /// The amount spent in the current period, including this one
+    pub spent: u64,
+    /// The timestamp when the current period started
+    pub last_reset: u64
 }
 
 /// Event emitted when a transaction is proposed
 pub struct TransactionProposed{
     pub tx_id: TxId,
+    pub proposer: Identity,
     pub to: Identity,
+    pub valid_until: u64,
     pub transaction_parameters: TransactionParameters,
+    pub config_epoch: u64,
+    pub gas_refund: Option<GasRefund>,
+    /// The weight of the proposer, which approves the transaction when proposing it
+    pub approvals_count: Approvals
+}
+
+/// Event emitted when a transaction reaches the threshold and its execution delay starts
+pub struct TransactionQueued{
+    pub tx_id: TxId,
+    pub queued_at: u64,
+    /// The timestamp from which the transaction can be executed
+    pub executable_at: u64
 }
 
 /// Event emitted when a transaction is executed
 pub struct TransactionExecuted{
-    pub tx_id: TxId
+    pub tx_id: TxId,
+    pub executor: Identity
+}
+
+/// Event emitted when the executor of a transaction is refunded the gas
+pub struct GasRefunded{
+    pub tx_id: TxId,
+    pub executor: Identity,
+    pub asset_id: AssetId,
+    pub amount: u64
 }
 
-/// Event emitted when a transaction is cancelled
+/// Event emitted when a pending transaction is cancelled
 pub struct TransactionCancelled{
-    pub tx_id: TxId
+    pub tx_id: TxId,
+    pub reason: CancellationReason
 }
 
 /// Event emitted when a transaction is removed
 pub struct TransactionRemoved{
-    pub tx_id: TxId
+    pub tx_id: TxId,
+    pub status: TransactionStatus,
+    pub finalized_by: Identity
 }
 
 /// Event emitted when a transaction is approved
 pub struct TransactionApproved{
     pub tx_id: TxId,
-    pub owner: Identity
+    pub owner: Identity,
+    pub weight: Weight,
+    pub approvals_count: Approvals,
+    pub rejections_count: Rejections
 }
 
 /// Event emitted when a transaction is rejected
 pub struct TransactionRejected{
     pub tx_id: TxId,
-    pub owner: Identity
-}
\ No newline at end of file
+    pub owner: Identity,
+    pub weight: Weight,
+    pub approvals_count: Approvals,
+    pub rejections_count: Rejections
+}
+
+/// Event emitted when an owner revokes its vote on a transaction
+pub struct VoteRevoked{
+    pub tx_id: TxId,
+    pub owner: Identity,
+    /// Whether the revoked vote was an approval or a rejection
+    pub was_approval: bool,
+    pub weight: Weight,
+    pub approvals_count: Approvals,
+    pub rejections_count: Rejections
+}
+
+/// Event emitted when a module is enabled
+pub struct ModuleEnabled{
+    pub module: ContractId
+}
+
+/// Event emitted when a module is disabled
+pub struct ModuleDisabled{
+    pub module: ContractId
+}
+
+/// Event emitted when a module executes a transaction
+pub struct ModuleExecuted{
+    pub module: ContractId,
+    pub to: Identity,
+    pub transaction_parameters: TransactionParameters
+}
+
+/// Event emitted when the guardians are changed
+pub struct GuardiansChanged{
+    pub guardians: Vec<Identity>,
+    pub threshold: u64,
+    pub recovery_delay: u64
+}
+
+/// Event emitted when a guardian starts a recovery
+pub struct RecoveryInitiated{
+    pub recovery_id: u64,
+    pub guardian: Identity,
+    pub new_owners: Vec<Owner>,
+    pub new_threshold: Weight
+}
+
+/// Event emitted when a guardian approves a recovery
+pub struct RecoveryApproved{
+    pub recovery_id: u64,
+    pub guardian: Identity,
+    pub approvals: u64,
+    /// The timestamp when the guardian threshold was reached, if it has been reached
+    pub approved_at: Option<u64>
+}
+
+/// Event emitted when a recovery is cancelled
+pub struct RecoveryCancelled{
+    pub recovery_id: u64,
+    pub cancelled_by: Identity
+}
+
+/// Event emitted when a recovery is finalized and the owners are replaced
+pub struct RecoveryFinalized{
+    pub recovery_id: u64,
+    pub new_owners: Vec<Owner>,
+    pub new_threshold: Weight,
+    pub config_epoch: u64
+}
+
+/// Event emitted when the inactivity switch is configured
+pub struct InactivitySwitchChanged{
+    pub beneficiary: Option<Identity>,
+    pub inactivity_period: u64,
+    pub assets: Vec<AssetId>
+}
+
+/// Event emitted for each asset transferred to the beneficiary after the owners have been inactive
+pub struct InactivityClaimed{
+    pub beneficiary: Identity,
+    pub asset_id: AssetId,
+    pub amount: u64,
+    /// The timestamp of the last activity of the owners
+    pub last_activity: u64
+}
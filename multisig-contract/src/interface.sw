@@ -1,41 +1,151 @@
 library;
 
 use std::bytes::Bytes;
+use std::b512::B512;
+use std::asset_id::AssetId;
 use ::types::*;
 abi Multisig {
     #[storage(read, write)]
-    fn constructor(threshold: u8, owners_list: Vec<Identity>);
+    fn constructor(threshold: Weight, owners_list: Vec<Owner>);
     #[storage(read, write)]
     fn propose_tx(to:Identity, tx_validity_duration: u64, tx_parameters: TransactionParameters) -> TxId;
     #[storage(read, write)]
+    fn propose_tx_with_gas_refund(to: Identity, tx_validity_duration: u64, tx_parameters: TransactionParameters, gas_refund: GasRefund) -> TxId;
+    #[storage(read, write)]
     fn approve_tx(tx_id: TxId);
     #[storage(read, write)]
+    fn approve_tx_with_signatures(tx_id: TxId, signatures: Vec<B512>);
+    #[storage(read, write)]
     fn reject_tx(tx_id: TxId);
     #[storage(read, write)]
+    fn revoke_vote(tx_id: TxId);
+    #[storage(read, write)]
+    fn change_vote(tx_id: TxId);
+    #[storage(read, write)]
     fn execute_tx(tx_id: TxId);
     #[storage(read, write)]
+    fn cancel_tx(tx_id: TxId);
+    #[storage(read, write)]
     fn remove_tx(tx_id: TxId);
     #[storage(read, write)]
-    fn add_owner(owner: Identity);
+    fn add_owner(owner: Identity, weight: Weight);
     #[storage(read, write)]
     fn remove_owner(owner: Identity);
     #[storage(read, write)]
-    fn change_threshold(threshold: u8);
+    fn swap_owner(old_owner: Identity, new_owner: Identity);
+    #[storage(read, write)]
+    fn set_owner_weight(owner: Identity, weight: Weight);
+    #[storage(read, write)]
+    fn change_threshold(threshold: Weight);
+    #[storage(read, write)]
+    fn update_config(add: Vec<Owner>, remove: Vec<Identity>, new_threshold: Option<Weight>);
+    #[storage(read, write)]
+    fn set_execution_delay(delay: u64);
+    #[storage(read, write)]
+    fn set_executor_policy(policy: ExecutorPolicy);
+    #[storage(read, write)]
+    fn set_roles(identity: Identity, roles: Roles);
+    #[storage(read, write)]
+    fn set_allowance(delegate: Identity, asset_id: AssetId, amount: u64, reset_period: u64);
+    #[storage(read, write)]
+    fn spend_allowance(asset_id: AssetId, to: Identity, amount: u64);
+    #[storage(read, write)]
+    fn enable_module(module: ContractId);
+    #[storage(read, write)]
+    fn disable_module(module: ContractId);
+    #[storage(read)]
+    fn exec_from_module(to: Identity, tx_parameters: TransactionParameters);
+    #[storage(read, write)]
+    fn set_guard(guard: Option<ContractId>);
+    #[storage(read, write)]
+    fn set_guardians(guardians: Vec<Identity>, threshold: u64, recovery_delay: u64);
+    #[storage(read, write)]
+    fn initiate_recovery(new_owners: Vec<Owner>, new_threshold: Weight);
+    #[storage(read, write)]
+    fn approve_recovery();
+    #[storage(read, write)]
+    fn cancel_recovery();
+    #[storage(read, write)]
+    fn finalize_recovery();
+    #[storage(read, write)]
+    fn set_inactivity_switch(beneficiary: Option<Identity>, inactivity_period: u64, assets: Vec<AssetId>);
+    #[storage(read)]
+    fn claim_inactive();
 }
 
 abi Info {
     #[storage(read)]
-    fn get_owners() -> Vec<Identity>;
+    fn get_owners() -> Vec<Owner>;
     #[storage(read)]
     fn is_owner(owner: Identity) -> bool;
     #[storage(read)]
-    fn get_threshold() -> u8;
+    fn get_roles(identity: Identity) -> Option<Roles>;
+    #[storage(read)]
+    fn get_allowance(delegate: Identity, asset_id: AssetId) -> Option<Allowance>;
+    #[storage(read)]
+    fn get_threshold() -> Weight;
+    #[storage(read)]
+    fn get_total_weight() -> Weight;
+    #[storage(read)]
+    fn get_execution_delay() -> u64;
+    #[storage(read)]
+    fn get_executor_policy() -> ExecutorPolicy;
+    #[storage(read)]
+    fn get_modules() -> Vec<ContractId>;
+    #[storage(read)]
+    fn is_module(module: ContractId) -> bool;
+    #[storage(read)]
+    fn get_guard() -> Option<ContractId>;
+    #[storage(read)]
+    fn get_guardians() -> Vec<Identity>;
+    #[storage(read)]
+    fn get_guardian_threshold() -> u64;
+    #[storage(read)]
+    fn get_recovery_delay() -> u64;
+    #[storage(read)]
+    fn get_recovery() -> Option<RecoveryData>;
+    #[storage(read)]
+    fn get_last_activity() -> u64;
+    #[storage(read)]
+    fn get_beneficiary() -> Option<Identity>;
+    #[storage(read)]
+    fn get_inactivity_period() -> u64;
+    #[storage(read)]
+    fn get_inactivity_assets() -> Vec<AssetId>;
     #[storage(read)]
     fn get_next_tx_id() -> TxId;
     #[storage(read)]
+    fn get_config_epoch() -> u64;
+    #[storage(read)]
     fn get_active_tx_ids() -> Vec<TxId>;
     #[storage(read)]
+    fn get_active_tx_ids_page(offset: u64, limit: u64) -> Vec<TxId>;
+    #[storage(read)]
+    fn get_txs_page(offset: u64, limit: u64) -> Vec<TransactionData>;
+    #[storage(read)]
     fn get_tx(tx_id: TxId) -> Option<TransactionData>;
     #[storage(read)]
     fn get_tx_approval_by_owner(tx_id: TxId, owner: Identity) -> Option<bool>;
+    #[storage(read)]
+    fn get_tx_queued_at(tx_id: TxId) -> Option<u64>;
+    #[storage(read)]
+    fn get_tx_status(tx_id: TxId) -> Option<TransactionStatus>;
+    #[storage(read)]
+    fn get_tx_record(tx_id: TxId) -> Option<TransactionRecord>;
+    #[storage(read)]
+    fn get_history_len() -> u64;
+    #[storage(read)]
+    fn get_history_page(offset: u64, limit: u64) -> Vec<TransactionRecord>;
+    #[storage(read)]
+    fn get_tx_signature_nonce(tx_id: TxId) -> u64;
+    #[storage(read)]
+    fn get_tx_hash(tx_id: TxId) -> Option<b256>;
+}
+
+/// The interface of the contracts that can be set as the guard of the multisig wallet.
+abi Guard {
+    /// Called before executing a transaction. Returns false to block the execution.
+    fn check_transaction(tx: TransactionData, executor: Identity) -> bool;
+    /// Called after executing a transaction. Returns false to revert the execution.
+    fn check_after_execution(tx: TransactionData) -> bool;
 }
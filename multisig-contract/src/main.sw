@@ -11,8 +11,16 @@ use errors::MultisigError;
 use events::*;
 use std::{
     asset::transfer,
-    context::this_balance,
-    hash::Hash,
+    b512::B512,
+    context::{
+        gas,
+        this_balance,
+    },
+    ecr::ec_recover_address,
+    hash::{
+        Hash,
+        Hasher,
+    },
     low_level_call::{
         call_with_function_selector,
         CallParams,
@@ -23,35 +31,106 @@ use std::{
 };
 use std::bytes::Bytes;
 
+configurable {
+    /// The maximum number of active transactions. If 0, the number of active transactions is unbounded.
+    MAX_TRANSACTIONS: u64 = 0,
+    /// If true, transactions proposed before the last change of the owners or the threshold can not be executed.
+    INVALIDATE_STALE_PROPOSALS: bool = false,
+}
+
+// The storage is namespaced so the multisig can run behind the SRC-14 proxy without clobbering its slots.
+// The slot of each field depends on its position in the block, so once deployed behind the proxy new fields
+// must be appended at the end, and existing fields must never be removed nor reordered.
+#[namespace(fuel_multisig)]
 storage {
     /// List of Owners of the multisig wallet.
     owners_list: StorageVec<Identity> = StorageVec {},
-    /// Owners of the multisig wallet.
-    owners: StorageMap<Identity, ()> = StorageMap {},
+    /// Owners of the multisig wallet and the weight of their votes.
+    owners: StorageMap<Identity, Weight> = StorageMap {},
+    /// The sum of the weights of all the owners.
+    total_weight: Weight = 0,
+    /// The roles of the owners and of any other identity allowed to interact with the multisig wallet.
+    roles: StorageMap<Identity, Roles> = StorageMap {},
+    /// The allowances of the delegates for each asset.
+    allowances: StorageMap<(Identity, AssetId), Allowance> = StorageMap {},
     /// The nonce of the multisig wallet for the next transaction.
     next_tx_id: TxId = 0,
-    /// The number of approvals required in order to execute a transaction.
-    threshold: u8 = 0,
+    /// The weight of approvals required in order to execute a transaction.
+    threshold: Weight = 0,
+    /// The delay in seconds between a transaction reaching the threshold and being executable.
+    execution_delay: u64 = 0,
+    /// Determines who can execute the approved transactions
+    executor_policy: ExecutorPolicy = ExecutorPolicy::Allowlist,
+    /// The list of enabled modules
+    modules_list: StorageVec<ContractId> = StorageVec {},
+    /// The modules that can execute transactions without the approval of the owners
+    modules: StorageMap<ContractId, bool> = StorageMap {},
+    /// The contract that checks the transactions before and after their execution
+    guard: Option<ContractId> = None,
+    /// The list of guardians that can recover the multisig wallet
+    guardians_list: StorageVec<Identity> = StorageVec {},
+    /// The guardians that can recover the multisig wallet
+    guardians: StorageMap<Identity, bool> = StorageMap {},
+    /// The number of guardians required to approve a recovery
+    guardian_threshold: u64 = 0,
+    /// The delay in seconds between the guardians approving a recovery and it being finalizable
+    recovery_delay: u64 = 0,
+    /// The id of the next recovery
+    next_recovery_id: u64 = 0,
+    /// The recovery in progress, if any
+    recovery: Option<Recovery> = None,
+    /// The owners set when the recovery in progress is finalized
+    recovery_owners: StorageVec<Owner> = StorageVec {},
+    /// The guardians that approved each recovery
+    recovery_approvals: StorageMap<(u64, Identity), bool> = StorageMap {},
+    /// The timestamp of the last action of an owner
+    last_activity: u64 = 0,
+    /// The identity that receives the assets when the owners have been inactive, if any
+    beneficiary: Option<Identity> = None,
+    /// The number of seconds without owner activity after which the assets can be claimed
+    inactivity_period: u64 = 0,
+    /// The assets transferred to the beneficiary when the owners have been inactive
+    inactivity_assets: StorageVec<AssetId> = StorageVec {},
+    /// Incremented every time the owners or the threshold change
+    config_epoch: u64 = 0,
+    /// The timestamp of the last change of the owners or the threshold
+    config_changed_at: u64 = 0,
     /// The list of transaction ids that are currently active.
     tx_ids_list: StorageVec<TxId> = StorageVec {},
+    /// The position of each active transaction id in the tx_ids_list.
+    tx_ids_index: StorageMap<TxId, u64> = StorageMap {},
     /// The transactions that are currently active.
     txs: StorageMap<TxId, Transaction> = StorageMap {},
+    /// The list of transaction ids that are no longer active, in the order they were finalized.
+    history_tx_ids: StorageVec<TxId> = StorageVec {},
+    /// The archived records of the transactions that are no longer active.
+    txs_history: StorageMap<TxId, TransactionRecord> = StorageMap {},
     // TODO: This is a workaround. We should use the calldata and function_selector from ContractCallParams directly instead of storing them in a separate storage key
     /// The calldata of the transactions that are currently active.(Optional)
     txs_calldata: StorageMap<TxId, StorageBytes> = StorageMap {},
     /// The function selector of the transactions that are currently active.(Optional)
     txs_function_selector: StorageMap<TxId, StorageBytes> = StorageMap {},
+    /// The operations of the batch transactions that are currently active.
+    txs_batch_operations: StorageMap<TxId, StorageVec<InternalBatchOperation>> = StorageMap::<TxId, StorageVec<InternalBatchOperation>> {},
+    /// The calldata of each operation of the batch transactions that are currently active.(Optional)
+    txs_batch_calldata: StorageMap<(TxId, u64), StorageBytes> = StorageMap {},
+    /// The function selector of each operation of the batch transactions that are currently active.(Optional)
+    txs_batch_function_selector: StorageMap<(TxId, u64), StorageBytes> = StorageMap {},
     /// Mapping of approvals to check which owner has approved or rejected a transaction.
     approvals: StorageMap<TxId, StorageMap<Identity, bool>> = StorageMap::<TxId, StorageMap<Identity, bool>> {},
-    /// Mapping of approvals count to check how many approvals a transaction has
-    approvals_count: StorageMap<TxId, u8> = StorageMap {},
-    /// Mapping of rejections count to check how many rejections a transaction has
-    rejections_count: StorageMap<TxId, u8> = StorageMap {},
+    /// Mapping of the ownership nonce each owner voted with, so the votes cast before an owner was removed are ignored.
+    vote_nonces: StorageMap<TxId, StorageMap<Identity, u64>> = StorageMap::<TxId, StorageMap<Identity, u64>> {},
+    /// Incremented every time an identity stops being an owner, which invalidates its votes on the pending transactions.
+    ownership_nonces: StorageMap<Identity, u64> = StorageMap {},
+    /// Mapping of the timestamps at which the transactions reached the threshold.
+    txs_queued_at: StorageMap<TxId, u64> = StorageMap {},
+    /// Incremented every time a vote on a transaction is revoked, so the signatures collected before can't be replayed.
+    txs_signature_nonce: StorageMap<TxId, u64> = StorageMap {},
 }
 
 impl Multisig for Contract {
     #[storage(read, write)]
-    fn constructor(threshold: u8, owners_list: Vec<Identity>) {
+    fn constructor(threshold: Weight, owners_list: Vec<Owner>) {
         // Check that the multisig wallet has not been initialized yet, otherwise revert
         require(
             storage
@@ -68,13 +147,6 @@ impl Multisig for Contract {
         // Check that the owners list is not empty, otherwise revert
         require(owners_count > 0, MultisigError::OwnersCannotBeEmpty);
 
-        // Check that the threshold is not greater than the owners count, otherwise revert
-        require(
-            owners_count >= threshold
-                .as_u64(),
-            MultisigError::ThresholdCannotBeGreaterThanOwners,
-        );
-
         // Check owners limit and revert if it has been reached
         require(
             owners_count <= MAX_OWNERS
@@ -83,19 +155,35 @@ impl Multisig for Contract {
         );
 
         // Add the owners
+        let mut total_weight = 0;
         let mut i = 0;
         while i < owners_count {
-            let owner = storage.owners.get(owners_list.get(i).unwrap()).try_read();
-            require(owner.is_none(), MultisigError::DuplicatedOwner);
+            let owner = owners_list.get(i).unwrap();
+
+            let owner_exists = storage.owners.get(owner.identity).try_read();
+            require(owner_exists.is_none(), MultisigError::DuplicatedOwner);
 
-            storage.owners.insert(owners_list.get(i).unwrap(), ());
+            // Check that the weight is not 0, otherwise revert
+            require(owner.weight != 0, MultisigError::WeightCannotBeZero);
+
+            storage.owners.insert(owner.identity, owner.weight);
+            storage.owners_list.push(owner.identity);
+            storage.roles.insert(owner.identity, Roles::all());
+            total_weight += owner.weight;
 
             i += 1;
         }
-        storage.owners_list.store_vec(owners_list);
 
-        // Set the threshold
+        // Check that the threshold is not greater than the total weight, otherwise revert
+        require(
+            total_weight >= threshold,
+            MultisigError::ThresholdCannotBeGreaterThanOwners,
+        );
+
+        // Set the total weight and the threshold
+        storage.total_weight.write(total_weight);
         storage.threshold.write(threshold);
+        storage.last_activity.write(block_timestamp());
 
         // Emit event
         log(MultisigInitialized {
@@ -110,123 +198,172 @@ impl Multisig for Contract {
         // Check that the multisig wallet has been initialized, otherwise revert
         require(storage.threshold.read() != 0, MultisigError::NotInitialized);
 
-        // Check that the number of transactions has not reached the limit, otherwise revert
-        require(storage.tx_ids_list.len() < MAX_TRANSACTIONS.as_u64(), MultisigError::MaxTransactionsReached);
-        
-        // Get the caller if it is an owner. If not, revert.
-        let caller = get_caller_if_owner();
+        _propose_tx(to, tx_validity_duration, tx_parameters, None)
+    }
 
-        // Get the next transaction id and increment the nonce
-        let tx_id = storage.next_tx_id.read();
-        storage.next_tx_id.write(tx_id + 1);
+    #[storage(read, write)]
+    fn propose_tx_with_gas_refund(
+        to: Identity,
+        tx_validity_duration: u64,
+        tx_parameters: TransactionParameters,
+        gas_refund: GasRefund,
+    ) -> TxId {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
 
-        // Calculate the valid_until timestamp
-        let valid_until = block_timestamp() + tx_validity_duration;
+        // The executor is refunded the gas consumed by the execution, up to the maximum amount
+        _propose_tx(to, tx_validity_duration, tx_parameters, Some(gas_refund))
+    }
 
-        // Store the transaction
-        storage.tx_ids_list.push(tx_id);
+    #[storage(read, write)]
+    fn approve_tx(tx_id: TxId) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
 
-        // TODO: This is a workaround. We should use the calldata and function_selector from ContractCallParams directly instead of storing them in a separate storage key
-        let internal_tx_parameters = match tx_parameters {
-            TransactionParameters::Call(contract_call_params) => {
-                let calldata = storage.txs_calldata.get(tx_id);
-                calldata.write_slice(contract_call_params.calldata);
+        // Check that the tx_id is valid, otherwise revert
+        check_tx_id_validity(tx_id);
 
-                let function_selector = storage.txs_function_selector.get(tx_id);
-                function_selector.write_slice(contract_call_params.function_selector);
+        // Get the caller if it has the approver role. If not, revert.
+        let (caller, roles) = get_caller_roles();
+        require(roles.approver, MultisigError::NotApprover);
 
-                InternalTransactionParameters::Call(InternalContractCallParams {
-                    forwarded_gas: contract_call_params.forwarded_gas,
-                    transfer_params: contract_call_params.transfer_params,
-                })
-            },
-            TransactionParameters::Transfer(transfer_params) => {
-                InternalTransactionParameters::Transfer(transfer_params)
-            },
-        };
+        // Check if the transaction is stil valid, otherwise revert
+        require(!check_if_tx_expired(tx_id), MultisigError::TransactionExpired);
 
-        storage
-            .txs
-            .insert(
-                tx_id,
-                Transaction {
-                    tx_id,
-                    to,
-                    valid_until,
-                    tx_parameters: internal_tx_parameters,
+        // Approve the transaction on behalf of the caller
+        _approve_tx(tx_id, caller);
+
+        // Record the activity of the caller if it is an owner
+        record_owner_activity();
+    }
+
+    #[storage(read, write)]
+    fn approve_tx_with_signatures(tx_id: TxId, signatures: Vec<B512>) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        // Check that the tx_id is valid, otherwise revert
+        check_tx_id_validity(tx_id);
+
+        // Check if the transaction is stil valid, otherwise revert
+        require(!check_if_tx_expired(tx_id), MultisigError::TransactionExpired);
+
+        // Every owner signs the same hash of the proposal
+        let tx_hash = compute_tx_hash(storage.txs.get(tx_id).try_read().unwrap());
+
+        let mut i = 0;
+        while i < signatures.len() {
+            // Recover the signer, otherwise revert
+            let signer = match ec_recover_address(signatures.get(i).unwrap(), tx_hash) {
+                Ok(address) => Identity::Address(address),
+                Err(_) => {
+                    require(false, MultisigError::InvalidSignature);
+                    revert(0); //TODO: This was added to make the compiler happy. This code is unreachable.
                 },
+            };
+
+            // Check if the signer is an owner, otherwise revert
+            require(
+                storage
+                    .owners
+                    .get(signer)
+                    .try_read()
+                    .is_some(),
+                MultisigError::NotOwner,
             );
 
-        // Initialize the approvals and rejections count
-        storage.approvals_count.insert(tx_id, 1);
-        storage.rejections_count.insert(tx_id, 0);
-        storage.approvals.get(tx_id).insert(caller, true);
+            // Check if the signer has the approver role, otherwise revert
+            require(
+                storage
+                    .roles
+                    .get(signer)
+                    .read()
+                    .approver,
+                MultisigError::NotApprover,
+            );
 
-        // Emit event
-        log(TransactionProposed {
-            tx_id: tx_id,
-            to: to,
-            transaction_parameters: tx_parameters,
-        });
-        tx_id
+            // Approve the transaction on behalf of the signer
+            _approve_tx(tx_id, signer);
+
+            i += 1;
+        }
+
+        // The signatures of the owners count as their activity
+        if signatures.len() > 0 {
+            storage.last_activity.write(block_timestamp());
+        }
     }
 
     #[storage(read, write)]
-    fn approve_tx(tx_id: TxId) {
+    fn reject_tx(tx_id: TxId) {
         // Check that the multisig wallet has been initialized, otherwise revert
         require(storage.threshold.read() != 0, MultisigError::NotInitialized);
 
         // Check that the tx_id is valid, otherwise revert
         check_tx_id_validity(tx_id);
 
-        // Get the caller if it is an owner. If not, revert.
-        let caller = get_caller_if_owner();
+        // Get the caller if it has the rejecter role. If not, revert.
+        let (caller, roles) = get_caller_roles();
+        require(roles.rejecter, MultisigError::NotRejecter);
 
         // Check if the transaction is stil valid, otherwise revert
         require(!check_if_tx_expired(tx_id), MultisigError::TransactionExpired);
 
-        // Check if the owner has already voted, otherwise revert
-        check_if_already_voted(tx_id, caller);
-
-        let approvals_count = storage.approvals_count.get(tx_id).read();
-        storage.approvals_count.insert(tx_id, approvals_count + 1);
+        // Reject the transaction on behalf of the caller
+        _reject_tx(tx_id, caller);
 
-        storage.approvals.get(tx_id).insert(caller, true);
-
-        // Emit event
-        log(TransactionApproved {
-            tx_id: tx_id,
-            owner: caller,
-        });
+        // Record the activity of the caller if it is an owner
+        record_owner_activity();
     }
 
     #[storage(read, write)]
-    fn reject_tx(tx_id: TxId) {
+    fn revoke_vote(tx_id: TxId) {
         // Check that the multisig wallet has been initialized, otherwise revert
         require(storage.threshold.read() != 0, MultisigError::NotInitialized);
 
         // Check that the tx_id is valid, otherwise revert
         check_tx_id_validity(tx_id);
 
-        // Get the caller if it is an owner. If not, revert.
-        let caller = get_caller_if_owner();
+        let caller = match msg_sender() {
+            Ok(caller) => caller,
+            Err(_) => revert(0),
+        };
 
         // Check if the transaction is stil valid, otherwise revert
         require(!check_if_tx_expired(tx_id), MultisigError::TransactionExpired);
 
-        // Check if the owner has already voted, otherwise revert
-        check_if_already_voted(tx_id, caller);
+        // Revoke the vote of the caller, reverting if it has not voted
+        let _ = _revoke_vote(tx_id, caller);
+
+        // Record the activity of the caller if it is an owner
+        record_owner_activity();
+    }
+
+    #[storage(read, write)]
+    fn change_vote(tx_id: TxId) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
 
-        let rejections_count = storage.rejections_count.get(tx_id).read();
-        storage.rejections_count.insert(tx_id, rejections_count + 1);
+        // Check that the tx_id is valid, otherwise revert
+        check_tx_id_validity(tx_id);
 
-        storage.approvals.get(tx_id).insert(caller, false);
+        // Get the caller roles. If it has none, revert.
+        let (caller, roles) = get_caller_roles();
 
-        // Emit event
-        log(TransactionRejected {
-            tx_id: tx_id,
-            owner: caller,
-        });
+        // Check if the transaction is stil valid, otherwise revert
+        require(!check_if_tx_expired(tx_id), MultisigError::TransactionExpired);
+
+        // Revoke the vote of the caller and cast the opposite one
+        if _revoke_vote(tx_id, caller) {
+            require(roles.rejecter, MultisigError::NotRejecter);
+            _reject_tx(tx_id, caller);
+        } else {
+            require(roles.approver, MultisigError::NotApprover);
+            _approve_tx(tx_id, caller);
+        }
+
+        // Record the activity of the caller if it is an owner
+        record_owner_activity();
     }
 
     #[storage(read, write)]
@@ -234,6 +371,9 @@ impl Multisig for Contract {
         // Check that the multisig wallet has been initialized, otherwise revert
         require(storage.threshold.read() != 0, MultisigError::NotInitialized);
 
+        // Check that the caller is allowed to execute by the executor policy, otherwise revert
+        check_executor_policy();
+
         // Check that the tx_id is valid, otherwise revert
         check_tx_id_validity(tx_id);
 
@@ -244,7 +384,7 @@ impl Multisig for Contract {
         let threshold = storage.threshold.read();
 
         // Get the tx approvals count
-        let approvals_count = storage.approvals_count.get(tx_id).read();
+        let (approvals_count, _) = get_votes_count(tx_id);
 
         // If the tx has been approved by the required number of owners, execute it, otherwise revert
         require(
@@ -252,17 +392,96 @@ impl Multisig for Contract {
             MultisigError::ThresholdNotReached,
         );
 
+        // Check that the execution delay has passed, otherwise revert
+        check_timelock(tx_id);
+
         // Get the transaction from the storage.
         let transaction = storage.txs.get(tx_id).try_read().unwrap();
 
+        // If stale proposals are invalidated, check that the owners and the threshold have not changed since the proposal, otherwise revert
+        require(
+            !INVALIDATE_STALE_PROPOSALS || transaction.config_epoch == storage.config_epoch.read(),
+            MultisigError::ConfigEpochChanged,
+        );
+
+        let executor = match msg_sender() {
+            Ok(caller) => caller,
+            Err(_) => revert(0),
+        };
+
+        // If there is a guard, check that it allows the execution, otherwise revert.
+        // Changing the guard is never checked, so a guard that blocks every transaction can still be replaced.
+        let guard = if is_guard_change(transaction) { None } else { storage.guard.read() };
+        let tx_data = get_tx_data(tx_id).unwrap();
+        if let Some(guard) = guard {
+            let guard = abi(Guard, guard.bits());
+            require(guard.check_transaction(tx_data, executor), MultisigError::TransactionBlockedByGuard);
+        }
+
         // Remove the transaction from active transactions
-        _remove_tx(tx_id);
+        _remove_tx(tx_id, TransactionStatus::Executed);
+
+        // Record the activity of the executor if it is an owner
+        record_owner_activity();
 
         // Execute the transaction
+        let gas_before = gas();
         _execute_tx(transaction);
+        let gas_used = gas_before - gas();
+
+        // If there is a guard, check that it allows the result of the execution, otherwise revert
+        if let Some(guard) = guard {
+            let guard = abi(Guard, guard.bits());
+            require(guard.check_after_execution(tx_data), MultisigError::TransactionBlockedByGuard);
+        }
+
+        // Emit event
+        log(TransactionExecuted {
+            tx_id: tx_id,
+            executor: executor,
+        });
+
+        // Refund the executor the gas consumed by the execution
+        if let Some(gas_refund) = transaction.gas_refund {
+            _refund_gas(tx_id, gas_refund, gas_used);
+        }
+    }
+
+    #[storage(read, write)]
+    fn cancel_tx(tx_id: TxId) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        // Check that the tx_id is valid, otherwise revert
+        check_tx_id_validity(tx_id);
+
+        let caller = match msg_sender() {
+            Ok(caller) => caller,
+            Err(_) => revert(0),
+        };
+
+        // Check that the caller is the proposer of the transaction, otherwise revert
+        let tx = storage.txs.get(tx_id).try_read().unwrap();
+        require(tx.proposer == caller, MultisigError::NotTransactionProposer);
+
+        // Check that the caller is still an owner or a proposer, otherwise revert
+        let roles = storage.roles.get(caller).try_read();
+        require(
+            storage.owners.get(caller).try_read().is_some() || (roles.is_some() && roles.unwrap().proposer),
+            MultisigError::NotProposer,
+        );
+
+        // Remove the transaction from active transactions
+        _remove_tx(tx_id, TransactionStatus::Cancelled);
+
+        // Record the activity of the caller if it is an owner
+        record_owner_activity();
 
         // Emit event
-        log(TransactionExecuted { tx_id: tx_id });
+        log(TransactionCancelled {
+            tx_id: tx_id,
+            reason: CancellationReason::CancelledByProposer,
+        });
     }
 
     #[storage(read, write)]
@@ -273,20 +492,34 @@ impl Multisig for Contract {
         // Check that the tx_id is valid, otherwise revert
         check_tx_id_validity(tx_id);
 
-        // If the transaction is still valid, revert
-        if(!check_if_tx_expired(tx_id) || check_if_threshold_can_be_reached(tx_id)) {
-           require(false, MultisigError::TransactionStillValid)
-        }
+        // If the transaction has not expired and its threshold can still be reached, revert
+        let expired = check_if_tx_expired(tx_id);
+        require(
+            expired || !check_if_threshold_can_be_reached(tx_id),
+            MultisigError::TransactionStillValid,
+        );
+
+        let (status, reason) = if expired {
+            (TransactionStatus::Expired, CancellationReason::Expired)
+        } else {
+            (TransactionStatus::Cancelled, CancellationReason::ThresholdUnreachable)
+        };
 
         // Remove the transaction from active transactions
-        _remove_tx(tx_id);
+        _remove_tx(tx_id, status);
+
+        // Record the activity of the caller if it is an owner
+        record_owner_activity();
 
         // Emit event
-        log(TransactionCancelled { tx_id: tx_id });
+        log(TransactionCancelled {
+            tx_id: tx_id,
+            reason: reason,
+        });
     }
 
     #[storage(read, write)]
-    fn add_owner(owner: Identity) {
+    fn add_owner(owner: Identity, weight: Weight) {
         // Check that the multisig wallet has been initialized, otherwise revert
         require(storage.threshold.read() != 0, MultisigError::NotInitialized);
 
@@ -301,16 +534,16 @@ impl Multisig for Contract {
             MultisigError::MaxOwnersReached,
         );
 
-        // Check that the owner is not already in the list, otherwise revert
-        let owner_exists = storage.owners.get(owner).try_read();
-        require(owner_exists.is_none(), MultisigError::AlreadyOwner);
-
-        // Add the owner
-        storage.owners.insert(owner, ());
-        storage.owners_list.push(owner);
+        // Add the owner, reverting if it already is an owner or its weight is 0
+        _add_owner(owner, weight);
+        let config_epoch = bump_config_epoch();
 
         // Emit event
-        log(OwnerAdded { owner: owner });
+        log(OwnerAdded {
+            owner: owner,
+            weight: weight,
+            config_epoch: config_epoch,
+        });
     }
 
     #[storage(read, write)]
@@ -327,36 +560,119 @@ impl Multisig for Contract {
             MultisigError::OwnersCannotBeEmpty,
         );
 
-        // Check that threshold is not greater than the remaining owners count after removing, otherwise revert
+        // Check that the owner is already in the list, otherwise revert
+        let owner_weight = storage.owners.get(owner).try_read();
+        require(owner_weight.is_some(), MultisigError::NotOwner);
+
+        // Check that threshold is not greater than the remaining total weight after removing, otherwise revert
+        let remaining_weight = storage.total_weight.read() - owner_weight.unwrap();
         require(
-            owners_count-1 >= storage.threshold.read().as_u64(),
+            remaining_weight >= storage.threshold.read(),
             MultisigError::ThresholdCannotBeGreaterThanOwners,
         );
 
-        // Check that the owner is already in the list, otherwise revert
-        let owner_exists = storage.owners.get(owner).try_read();
-        require(owner_exists.is_some(), MultisigError::NotOwner);
-
         // Remove the owner
         _remove_owner(owner);
+        let config_epoch = bump_config_epoch();
+
+        // Emit event
+        log(OwnerRemoved {
+            owner: owner,
+            config_epoch: config_epoch,
+        });
+    }
+
+    #[storage(read, write)]
+    fn swap_owner(old_owner: Identity, new_owner: Identity) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        check_self_call();
+
+        // Check that the old owner is in the list, otherwise revert
+        let weight = storage.owners.get(old_owner).try_read();
+        require(weight.is_some(), MultisigError::NotOwner);
+
+        // Check that the new owner is not already in the list, otherwise revert
+        let new_owner_exists = storage.owners.get(new_owner).try_read();
+        require(new_owner_exists.is_none(), MultisigError::AlreadyOwner);
+
+        // Invalidate the votes of the old owner on the pending transactions
+        invalidate_votes(old_owner);
+
+        // Replace the owner keeping its position in the list, its weight and its roles
+        let owners_list = storage.owners_list.load_vec();
+        let mut i = 0;
+        while i < owners_list.len() {
+            if owners_list.get(i).unwrap() == old_owner {
+                storage.owners_list.set(i, new_owner);
+                break;
+            }
+            i += 1;
+        }
+
+        let roles = storage.roles.get(old_owner).read();
+        let _ = storage.owners.remove(old_owner);
+        let _ = storage.roles.remove(old_owner);
+        storage.owners.insert(new_owner, weight.unwrap());
+        storage.roles.insert(new_owner, roles);
+        let config_epoch = bump_config_epoch();
 
         // Emit event
-        log(OwnerRemoved { owner: owner });
+        log(OwnerSwapped {
+            old_owner: old_owner,
+            new_owner: new_owner,
+            weight: weight.unwrap(),
+            config_epoch: config_epoch,
+        });
     }
 
     #[storage(read, write)]
-    fn change_threshold(threshold: u8) {
+    fn set_owner_weight(owner: Identity, weight: Weight) {
         // Check that the multisig wallet has been initialized, otherwise revert
         require(storage.threshold.read() != 0, MultisigError::NotInitialized);
 
         check_self_call();
 
-        // Check that the threshold is not greater than the owners count, otherwise revert
+        // Check that the owner is already in the list, otherwise revert
+        let old_weight = storage.owners.get(owner).try_read();
+        require(old_weight.is_some(), MultisigError::NotOwner);
+
+        // Check that the weight is not 0, otherwise revert
+        require(weight != 0, MultisigError::WeightCannotBeZero);
+
+        // Check that threshold is not greater than the new total weight, otherwise revert
+        let total_weight = storage.total_weight.read() - old_weight.unwrap() + weight;
         require(
-            threshold
-                .as_u64() <= storage
-                .owners_list
-                .len(),
+            total_weight >= storage.threshold.read(),
+            MultisigError::ThresholdCannotBeGreaterThanOwners,
+        );
+
+        // Change the weight
+        storage.owners.insert(owner, weight);
+        storage.total_weight.write(total_weight);
+        let config_epoch = bump_config_epoch();
+
+        // Emit event
+        log(OwnerWeightChanged {
+            owner: owner,
+            weight: weight,
+            config_epoch: config_epoch,
+        });
+    }
+
+    #[storage(read, write)]
+    fn change_threshold(threshold: Weight) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        check_self_call();
+
+        // Check that the threshold is not greater than the total weight, otherwise revert
+        require(
+            threshold <= storage
+                .total_weight
+                .read(),
             MultisigError::ThresholdCannotBeGreaterThanOwners,
         );
 
@@ -365,200 +681,1588 @@ impl Multisig for Contract {
 
         // Change the threshold
         storage.threshold.write(threshold);
+        let config_epoch = bump_config_epoch();
 
         // Emit event
         log(ThresholdChanged {
             new_threshold: threshold,
+            config_epoch: config_epoch,
         });
     }
-}
 
-impl Info for Contract{
-    #[storage(read)]
-    fn get_threshold() -> u8 {
-        storage.threshold.read()
-    }
+    #[storage(read, write)]
+    fn update_config(add: Vec<Owner>, remove: Vec<Identity>, new_threshold: Option<Weight>) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
 
-    #[storage(read)]
-    fn get_next_tx_id() -> TxId {
-        storage.next_tx_id.read()
-    }
+        check_self_call();
 
-    #[storage(read)]
-    fn get_owners() -> Vec<Identity> {
-        storage.owners_list.load_vec()
-    }
+        // The whole update is a single configuration change
+        let config_epoch = bump_config_epoch();
 
-    #[storage(read)]
-    fn is_owner(owner: Identity) -> bool {
-        storage.owners.get(owner).try_read().is_some()
-    }
+        // Remove the owners first, so a removed owner can be added back with a new weight
+        let mut i = 0;
+        while i < remove.len() {
+            let owner = remove.get(i).unwrap();
 
-    #[storage(read)]
-    fn get_active_tx_ids() -> Vec<TxId> {
-        storage.tx_ids_list.load_vec()
-    }
+            // Check that the owner is in the list, otherwise revert
+            require(storage.owners.get(owner).try_read().is_some(), MultisigError::NotOwner);
 
-    #[storage(read)]
-    fn get_tx(tx_id: TxId) -> Option<TransactionData> {
-        let tx = storage.txs.get(tx_id).try_read();
-
-        if let Some(tx) = tx {
-            let tx_parameters = match tx.tx_parameters {
-                InternalTransactionParameters::Call(contract_call_params) => {
-                    TransactionParameters::Call(ContractCallParams {
-                        calldata: storage.txs_calldata.get(tx_id).read_slice().unwrap(),
-                        forwarded_gas: contract_call_params.forwarded_gas,
-                        function_selector: storage.txs_function_selector.get(tx_id).read_slice().unwrap(),
-                        transfer_params: contract_call_params.transfer_params,
-                    })
-                },
-                InternalTransactionParameters::Transfer(transfer_params) => {
-                    TransactionParameters::Transfer(transfer_params)
-                },
-            };
+            _remove_owner(owner);
 
-            Some(TransactionData{
-                tx_id: tx_id,
-                to: tx.to,
-                valid_until: tx.valid_until,
-                tx_parameters: tx_parameters,
-                approvals_count: storage.approvals_count.get(tx_id).try_read().unwrap_or(0),
-                rejections_count: storage.rejections_count.get(tx_id).try_read().unwrap_or(0),
-            })
-        }
-        else {
-            return None;
+            // Emit event
+            log(OwnerRemoved {
+                owner: owner,
+                config_epoch: config_epoch,
+            });
+
+            i += 1;
         }
-    }
 
-    #[storage(read)]
-    fn get_tx_approval_by_owner(tx_id: TxId, owner: Identity) -> Option<bool> {
-        storage.approvals.get(tx_id).get(owner).try_read()
-    }
-}
+        let mut i = 0;
+        while i < add.len() {
+            let owner = add.get(i).unwrap();
 
-// Helper functions
-#[storage(read, write)]
-fn _remove_tx(tx_id: TxId) {
-    // Remove the transaction from active transactions
-    let tx_ids_list = storage.tx_ids_list.load_vec();
-    
-    let mut i = 0;
-    while i < tx_ids_list.len() {
-        if tx_ids_list.get(i).unwrap() == tx_id {
-            let _ = storage.tx_ids_list.remove(i);
-            break;
+            // Add the owner, reverting if it already is an owner or its weight is 0
+            _add_owner(owner.identity, owner.weight);
+
+            // Emit event
+            log(OwnerAdded {
+                owner: owner.identity,
+                weight: owner.weight,
+                config_epoch: config_epoch,
+            });
+
+            i += 1;
         }
-        i += 1;
-    }
 
-    let _ = storage.txs.remove(tx_id);
-    let _ = storage.txs_calldata.remove(tx_id);
-    let _ = storage.txs_function_selector.remove(tx_id);
-    let _ = storage.approvals.remove(tx_id);
-    let _ = storage.approvals_count.remove(tx_id);
-    let _ = storage.rejections_count.remove(tx_id);
+        // Only the final state is validated
+        let owners_count = storage.owners_list.len();
+        require(owners_count > 0, MultisigError::OwnersCannotBeEmpty);
+        require(owners_count <= MAX_OWNERS.as_u64(), MultisigError::MaxOwnersReached);
 
-    // Emit event
-    log(TransactionRemoved { tx_id: tx_id });
-}
+        let threshold = new_threshold.unwrap_or(storage.threshold.read());
+        require(threshold != 0, MultisigError::ThresholdCannotBeZero);
+        require(
+            threshold <= storage
+                .total_weight
+                .read(),
+            MultisigError::ThresholdCannotBeGreaterThanOwners,
+        );
 
-#[storage(read, write)]
-fn _remove_owner(owner: Identity) {
-    // Remove the owner from the mapping
-    let _ = storage.owners.remove(owner);
+        if let Some(new_threshold) = new_threshold {
+            // Change the threshold
+            storage.threshold.write(new_threshold);
 
-    // Remove the owner from the list
-    let owners_list = storage.owners_list.load_vec();
-    let mut i = 0;
-    while i < owners_list.len() {
-        if owners_list.get(i).unwrap() == owner {
-            let _ = storage.owners_list.remove(i);
-            break;
+            // Emit event
+            log(ThresholdChanged {
+                new_threshold: new_threshold,
+                config_epoch: config_epoch,
+            });
         }
-        i += 1;
+    }
+
+    #[storage(read, write)]
+    fn set_execution_delay(delay: u64) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        check_self_call();
+
+        // Change the execution delay
+        storage.execution_delay.write(delay);
+
+        // Emit event
+        log(ExecutionDelayChanged {
+            new_delay: delay,
+        });
+    }
+
+    #[storage(read, write)]
+    fn set_executor_policy(policy: ExecutorPolicy) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        check_self_call();
+
+        // Change the executor policy
+        storage.executor_policy.write(policy);
+
+        // Emit event
+        log(ExecutorPolicyChanged {
+            new_policy: policy,
+        });
+    }
+
+    #[storage(read, write)]
+    fn set_roles(identity: Identity, roles: Roles) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        check_self_call();
+
+        // Check that only owners are granted the roles to vote, as the votes of other identities are never counted, otherwise revert
+        require(
+            storage.owners.get(identity).try_read().is_some() || !(roles.approver || roles.rejecter),
+            MultisigError::NotOwner,
+        );
+
+        // Set the roles
+        storage.roles.insert(identity, roles);
+
+        // Emit event
+        log(RolesChanged {
+            identity: identity,
+            roles: roles,
+        });
+    }
+
+    #[storage(read, write)]
+    fn set_allowance(delegate: Identity, asset_id: AssetId, amount: u64, reset_period: u64) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        check_self_call();
+
+        // Set the allowance, starting a new period
+        storage.allowances.insert((delegate, asset_id), Allowance {
+            amount,
+            reset_period,
+            spent: 0,
+            last_reset: block_timestamp(),
+        });
+
+        // Emit event
+        log(AllowanceSet {
+            delegate: delegate,
+            asset_id: asset_id,
+            amount: amount,
+            reset_period: reset_period,
+        });
+    }
+
+    #[storage(read, write)]
+    fn spend_allowance(asset_id: AssetId, to: Identity, amount: u64) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        let caller = match msg_sender() {
+            Ok(caller) => caller,
+            Err(_) => revert(0),
+        };
+
+        // Get the allowance of the caller, otherwise revert
+        let allowance = storage.allowances.get((caller, asset_id)).try_read();
+        require(allowance.is_some(), MultisigError::AllowanceExceeded);
+        let mut allowance = allowance.unwrap();
+
+        // Start a new period if the current one has ended
+        let now = block_timestamp();
+        if allowance.reset_period != 0 && now >= allowance.last_reset + allowance.reset_period {
+            allowance.spent = 0;
+            allowance.last_reset = now;
+        }
+
+        // Check that the amount does not exceed the remaining allowance, otherwise revert
+        require(
+            allowance.spent + amount <= allowance.amount,
+            MultisigError::AllowanceExceeded,
+        );
+
+        // Check that the multisig has enough funds, otherwise revert
+        require(
+            amount <= this_balance(asset_id),
+            MultisigError::InsufficientAssetAmount,
+        );
+
+        allowance.spent += amount;
+        storage.allowances.insert((caller, asset_id), allowance);
+
+        // Record the activity of the caller if it is an owner
+        record_owner_activity();
+
+        transfer(to, asset_id, amount);
+
+        // Emit event
+        log(AllowanceSpent {
+            delegate: caller,
+            asset_id: asset_id,
+            to: to,
+            amount: amount,
+            spent: allowance.spent,
+            last_reset: allowance.last_reset,
+        });
+    }
+
+    #[storage(read, write)]
+    fn enable_module(module: ContractId) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        check_self_call();
+
+        // Check that the module is not already enabled, otherwise revert
+        require(storage.modules.get(module).try_read().is_none(), MultisigError::ModuleAlreadyEnabled);
+
+        // Enable the module
+        storage.modules.insert(module, true);
+        storage.modules_list.push(module);
+
+        // Emit event
+        log(ModuleEnabled { module: module });
+    }
+
+    #[storage(read, write)]
+    fn disable_module(module: ContractId) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        check_self_call();
+
+        // Check that the module is enabled, otherwise revert
+        require(storage.modules.get(module).try_read().is_some(), MultisigError::ModuleNotEnabled);
+
+        // Disable the module
+        let _ = storage.modules.remove(module);
+        let modules_list = storage.modules_list.load_vec();
+        let mut i = 0;
+        while i < modules_list.len() {
+            if modules_list.get(i).unwrap() == module {
+                let _ = storage.modules_list.remove(i);
+                break;
+            }
+            i += 1;
+        }
+
+        // Emit event
+        log(ModuleDisabled { module: module });
+    }
+
+    #[storage(read, write)]
+    fn set_guard(guard: Option<ContractId>) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        check_self_call();
+
+        // Change the guard
+        storage.guard.write(guard);
+
+        // Emit event
+        log(GuardChanged { guard: guard });
+    }
+
+    #[storage(read)]
+    fn exec_from_module(to: Identity, tx_parameters: TransactionParameters) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        // Check that the caller is an enabled module, otherwise revert
+        let module = match msg_sender() {
+            Ok(Identity::ContractId(caller)) => caller,
+            _ => {
+                require(false, MultisigError::Unauthorized);
+                revert(0); //TODO: This was added to make the compiler happy. This code is unreachable.
+            },
+        };
+        require(storage.modules.get(module).try_read().is_some(), MultisigError::Unauthorized);
+
+        // Check that a batch is not empty, otherwise revert
+        if let TransactionParameters::Batch(operations) = tx_parameters {
+            require(operations.len() > 0, MultisigError::EmptyBatch);
+        }
+
+        // If there is a guard, check that it allows the transaction, otherwise revert
+        let guard = storage.guard.read();
+        let tx_data = get_module_tx_data(module, to, tx_parameters);
+        if let Some(guard) = guard {
+            let guard = abi(Guard, guard.bits());
+            require(guard.check_transaction(tx_data, Identity::ContractId(module)), MultisigError::TransactionBlockedByGuard);
+        }
+
+        // Execute the transaction
+        _execute_parameters(to, tx_parameters);
+
+        // If there is a guard, check that it allows the result of the execution, otherwise revert
+        if let Some(guard) = guard {
+            let guard = abi(Guard, guard.bits());
+            require(guard.check_after_execution(tx_data), MultisigError::TransactionBlockedByGuard);
+        }
+
+        // Emit event
+        log(ModuleExecuted {
+            module: module,
+            to: to,
+            transaction_parameters: tx_parameters,
+        });
+    }
+
+    #[storage(read, write)]
+    fn set_guardians(guardians: Vec<Identity>, threshold: u64, recovery_delay: u64) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        check_self_call();
+
+        // Check that the threshold can be reached by the guardians, otherwise revert
+        let guardians_count = guardians.len();
+        require(
+            (guardians_count == 0 && threshold == 0) || (threshold != 0 && threshold <= guardians_count),
+            MultisigError::InvalidGuardianThreshold,
+        );
+
+        // Cancel the recovery in progress, as it was approved by the previous guardians
+        _cancel_recovery(Identity::ContractId(ContractId::this()));
+
+        // Remove the previous guardians
+        let previous_guardians = storage.guardians_list.load_vec();
+        let mut i = 0;
+        while i < previous_guardians.len() {
+            let _ = storage.guardians.remove(previous_guardians.get(i).unwrap());
+            i += 1;
+        }
+        storage.guardians_list.clear();
+
+        // Add the new guardians
+        let mut i = 0;
+        while i < guardians_count {
+            let guardian = guardians.get(i).unwrap();
+
+            // Check that the guardian is not duplicated, otherwise revert
+            require(storage.guardians.get(guardian).try_read().is_none(), MultisigError::DuplicatedGuardian);
+
+            storage.guardians.insert(guardian, true);
+            storage.guardians_list.push(guardian);
+
+            i += 1;
+        }
+
+        storage.guardian_threshold.write(threshold);
+        storage.recovery_delay.write(recovery_delay);
+
+        // Emit event
+        log(GuardiansChanged {
+            guardians: guardians,
+            threshold: threshold,
+            recovery_delay: recovery_delay,
+        });
+    }
+
+    #[storage(read, write)]
+    fn initiate_recovery(new_owners: Vec<Owner>, new_threshold: Weight) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        // Check that the caller is a guardian, otherwise revert
+        let guardian = get_guardian();
+
+        // Check that there is no recovery in progress, otherwise revert
+        require(storage.recovery.read().is_none(), MultisigError::RecoveryAlreadyInProgress);
+
+        // Check that the new owners and threshold are a valid configuration, otherwise revert
+        check_owners_config(new_owners, new_threshold);
+
+        let recovery_id = storage.next_recovery_id.read();
+        storage.next_recovery_id.write(recovery_id + 1);
+
+        let mut i = 0;
+        while i < new_owners.len() {
+            storage.recovery_owners.push(new_owners.get(i).unwrap());
+            i += 1;
+        }
+
+        storage.recovery.write(Some(Recovery {
+            recovery_id,
+            new_threshold,
+            approvals: 0,
+            approved_at: None,
+        }));
+
+        // Emit event
+        log(RecoveryInitiated {
+            recovery_id: recovery_id,
+            guardian: guardian,
+            new_owners: new_owners,
+            new_threshold: new_threshold,
+        });
+
+        // The guardian that starts the recovery approves it
+        _approve_recovery(guardian);
+    }
+
+    #[storage(read, write)]
+    fn approve_recovery() {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        // Check that the caller is a guardian, otherwise revert
+        let guardian = get_guardian();
+
+        // Approve the recovery on behalf of the guardian
+        _approve_recovery(guardian);
+    }
+
+    #[storage(read, write)]
+    fn cancel_recovery() {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        let caller = match msg_sender() {
+            Ok(caller) => caller,
+            Err(_) => revert(0),
+        };
+
+        // Check that the caller is an owner, otherwise revert
+        require(storage.owners.get(caller).try_read().is_some(), MultisigError::NotOwner);
+
+        // Check that there is a recovery in progress, otherwise revert
+        require(storage.recovery.read().is_some(), MultisigError::NoRecoveryInProgress);
+
+        _cancel_recovery(caller);
+        storage.last_activity.write(block_timestamp());
+    }
+
+    #[storage(read, write)]
+    fn finalize_recovery() {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        // Check that there is a recovery in progress, otherwise revert
+        let recovery = storage.recovery.read();
+        require(recovery.is_some(), MultisigError::NoRecoveryInProgress);
+        let recovery = recovery.unwrap();
+
+        // Check that the guardians approved the recovery and its delay has passed, otherwise revert
+        require(
+            recovery.approved_at.is_some() && block_timestamp() >= recovery.approved_at.unwrap() + storage.recovery_delay.read(),
+            MultisigError::RecoveryDelayNotExpired,
+        );
+
+        // Remove the current owners, dropping their votes on the pending transactions
+        while storage.owners_list.len() > 0 {
+            let owner = storage.owners_list.get(0).unwrap().read();
+            _remove_owner(owner);
+        }
+
+        // Add the new owners and set the new threshold
+        let new_owners = storage.recovery_owners.load_vec();
+        let mut i = 0;
+        while i < new_owners.len() {
+            let owner = new_owners.get(i).unwrap();
+            _add_owner(owner.identity, owner.weight);
+            i += 1;
+        }
+        storage.threshold.write(recovery.new_threshold);
+        let config_epoch = bump_config_epoch();
+
+        // The new owners get a full inactivity period
+        storage.last_activity.write(block_timestamp());
+
+        // Clear the recovery
+        storage.recovery.write(None);
+        storage.recovery_owners.clear();
+
+        // Emit event
+        log(RecoveryFinalized {
+            recovery_id: recovery.recovery_id,
+            new_owners: new_owners,
+            new_threshold: recovery.new_threshold,
+            config_epoch: config_epoch,
+        });
+    }
+
+    #[storage(read, write)]
+    fn set_inactivity_switch(beneficiary: Option<Identity>, inactivity_period: u64, assets: Vec<AssetId>) {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        check_self_call();
+
+        // Check that the inactivity period is not 0 when there is a beneficiary, otherwise revert
+        require(beneficiary.is_none() || inactivity_period != 0, MultisigError::InvalidInactivityPeriod);
+
+        storage.beneficiary.write(beneficiary);
+        storage.inactivity_period.write(inactivity_period);
+        storage.inactivity_assets.clear();
+        let mut i = 0;
+        while i < assets.len() {
+            storage.inactivity_assets.push(assets.get(i).unwrap());
+            i += 1;
+        }
+
+        // The owners approved this change, so the inactivity period starts now
+        storage.last_activity.write(block_timestamp());
+
+        // Emit event
+        log(InactivitySwitchChanged {
+            beneficiary: beneficiary,
+            inactivity_period: inactivity_period,
+            assets: assets,
+        });
+    }
+
+    #[storage(read)]
+    fn claim_inactive() {
+        // Check that the multisig wallet has been initialized, otherwise revert
+        require(storage.threshold.read() != 0, MultisigError::NotInitialized);
+
+        // Check that a beneficiary has been set, otherwise revert
+        let beneficiary = storage.beneficiary.read();
+        require(beneficiary.is_some(), MultisigError::InactivitySwitchNotSet);
+        let beneficiary = beneficiary.unwrap();
+
+        // Check that no owner has been active during the inactivity period, otherwise revert
+        let last_activity = storage.last_activity.read();
+        require(
+            block_timestamp() >= last_activity + storage.inactivity_period.read(),
+            MultisigError::OwnersStillActive,
+        );
+
+        // Transfer the whole balance of every configured asset to the beneficiary
+        let assets = storage.inactivity_assets.load_vec();
+        let mut i = 0;
+        while i < assets.len() {
+            let asset_id = assets.get(i).unwrap();
+            let amount = this_balance(asset_id);
+            if amount > 0 {
+                transfer(beneficiary, asset_id, amount);
+
+                // Emit event
+                log(InactivityClaimed {
+                    beneficiary: beneficiary,
+                    asset_id: asset_id,
+                    amount: amount,
+                    last_activity: last_activity,
+                });
+            }
+            i += 1;
+        }
+    }
+}
+
+impl Info for Contract{
+    #[storage(read)]
+    fn get_threshold() -> Weight {
+        storage.threshold.read()
+    }
+
+    #[storage(read)]
+    fn get_total_weight() -> Weight {
+        storage.total_weight.read()
+    }
+
+    #[storage(read)]
+    fn get_execution_delay() -> u64 {
+        storage.execution_delay.read()
+    }
+
+    #[storage(read)]
+    fn get_executor_policy() -> ExecutorPolicy {
+        storage.executor_policy.read()
+    }
+
+    #[storage(read)]
+    fn get_modules() -> Vec<ContractId> {
+        storage.modules_list.load_vec()
+    }
+
+    #[storage(read)]
+    fn is_module(module: ContractId) -> bool {
+        storage.modules.get(module).try_read().is_some()
+    }
+
+    #[storage(read)]
+    fn get_guard() -> Option<ContractId> {
+        storage.guard.read()
+    }
+
+    #[storage(read)]
+    fn get_guardians() -> Vec<Identity> {
+        storage.guardians_list.load_vec()
+    }
+
+    #[storage(read)]
+    fn get_guardian_threshold() -> u64 {
+        storage.guardian_threshold.read()
+    }
+
+    #[storage(read)]
+    fn get_recovery_delay() -> u64 {
+        storage.recovery_delay.read()
+    }
+
+    #[storage(read)]
+    fn get_recovery() -> Option<RecoveryData> {
+        match storage.recovery.read() {
+            Some(recovery) => Some(RecoveryData {
+                recovery_id: recovery.recovery_id,
+                new_owners: storage.recovery_owners.load_vec(),
+                new_threshold: recovery.new_threshold,
+                approvals: recovery.approvals,
+                approved_at: recovery.approved_at,
+            }),
+            None => None,
+        }
+    }
+
+    #[storage(read)]
+    fn get_last_activity() -> u64 {
+        storage.last_activity.read()
+    }
+
+    #[storage(read)]
+    fn get_beneficiary() -> Option<Identity> {
+        storage.beneficiary.read()
+    }
+
+    #[storage(read)]
+    fn get_inactivity_period() -> u64 {
+        storage.inactivity_period.read()
+    }
+
+    #[storage(read)]
+    fn get_inactivity_assets() -> Vec<AssetId> {
+        storage.inactivity_assets.load_vec()
+    }
+
+    #[storage(read)]
+    fn get_next_tx_id() -> TxId {
+        storage.next_tx_id.read()
+    }
+
+    #[storage(read)]
+    fn get_config_epoch() -> u64 {
+        storage.config_epoch.read()
+    }
+
+    #[storage(read)]
+    fn get_owners() -> Vec<Owner> {
+        let owners_list = storage.owners_list.load_vec();
+
+        let mut owners = Vec::new();
+        let mut i = 0;
+        while i < owners_list.len() {
+            let identity = owners_list.get(i).unwrap();
+            owners.push(Owner {
+                identity,
+                weight: get_owner_weight(identity),
+            });
+            i += 1;
+        }
+
+        owners
+    }
+
+    #[storage(read)]
+    fn is_owner(owner: Identity) -> bool {
+        storage.owners.get(owner).try_read().is_some()
+    }
+
+    #[storage(read)]
+    fn get_roles(identity: Identity) -> Option<Roles> {
+        storage.roles.get(identity).try_read()
+    }
+
+    #[storage(read)]
+    fn get_allowance(delegate: Identity, asset_id: AssetId) -> Option<Allowance> {
+        storage.allowances.get((delegate, asset_id)).try_read()
+    }
+
+    #[storage(read)]
+    fn get_active_tx_ids() -> Vec<TxId> {
+        storage.tx_ids_list.load_vec()
+    }
+
+    #[storage(read)]
+    fn get_tx(tx_id: TxId) -> Option<TransactionData> {
+        get_tx_data(tx_id)
+    }
+
+    #[storage(read)]
+    fn get_active_tx_ids_page(offset: u64, limit: u64) -> Vec<TxId> {
+        let mut tx_ids = Vec::new();
+
+        let mut i = offset;
+        while i < storage.tx_ids_list.len() && i - offset < limit {
+            tx_ids.push(storage.tx_ids_list.get(i).unwrap().read());
+            i += 1;
+        }
+
+        tx_ids
+    }
+
+    #[storage(read)]
+    fn get_txs_page(offset: u64, limit: u64) -> Vec<TransactionData> {
+        let mut txs = Vec::new();
+
+        let mut i = offset;
+        while i < storage.tx_ids_list.len() && i - offset < limit {
+            let tx_id = storage.tx_ids_list.get(i).unwrap().read();
+            txs.push(get_tx_data(tx_id).unwrap());
+            i += 1;
+        }
+
+        txs
+    }
+
+    #[storage(read)]
+    fn get_tx_approval_by_owner(tx_id: TxId, owner: Identity) -> Option<bool> {
+        get_vote(tx_id, owner)
+    }
+
+    #[storage(read)]
+    fn get_tx_queued_at(tx_id: TxId) -> Option<u64> {
+        get_queued_at(tx_id)
+    }
+
+    #[storage(read)]
+    fn get_tx_status(tx_id: TxId) -> Option<TransactionStatus> {
+        if storage.txs.get(tx_id).try_read().is_some() {
+            return Some(TransactionStatus::Pending);
+        }
+
+        match storage.txs_history.get(tx_id).try_read() {
+            Some(record) => Some(record.status),
+            None => None,
+        }
+    }
+
+    #[storage(read)]
+    fn get_tx_record(tx_id: TxId) -> Option<TransactionRecord> {
+        storage.txs_history.get(tx_id).try_read()
+    }
+
+    #[storage(read)]
+    fn get_history_len() -> u64 {
+        storage.history_tx_ids.len()
+    }
+
+    #[storage(read)]
+    fn get_history_page(offset: u64, limit: u64) -> Vec<TransactionRecord> {
+        let mut records = Vec::new();
+
+        let mut i = offset;
+        while i < storage.history_tx_ids.len() && i - offset < limit {
+            let tx_id = storage.history_tx_ids.get(i).unwrap().read();
+            records.push(storage.txs_history.get(tx_id).read());
+            i += 1;
+        }
+
+        records
+    }
+
+    #[storage(read)]
+    fn get_tx_signature_nonce(tx_id: TxId) -> u64 {
+        get_signature_nonce(tx_id)
+    }
+
+    #[storage(read)]
+    fn get_tx_hash(tx_id: TxId) -> Option<b256> {
+        match storage.txs.get(tx_id).try_read() {
+            Some(tx) => Some(compute_tx_hash(tx)),
+            None => None,
+        }
+    }
+}
+
+// Helper functions
+#[storage(read, write)]
+fn _propose_tx(
+    to: Identity,
+    tx_validity_duration: u64,
+    tx_parameters: TransactionParameters,
+    gas_refund: Option<GasRefund>,
+) -> TxId {
+    // Check that the number of transactions has not reached the limit, otherwise revert
+    require(MAX_TRANSACTIONS == 0 || storage.tx_ids_list.len() < MAX_TRANSACTIONS, MultisigError::MaxTransactionsReached);
+
+    // Check that the transaction can be executed at all, otherwise revert
+    check_tx_parameters(to, tx_parameters);
+    
+    // Get the caller if it has the proposer role. If not, revert.
+    let (caller, roles) = get_caller_roles();
+    require(roles.proposer, MultisigError::NotProposer);
+
+    // Record the activity of the caller if it is an owner
+    record_owner_activity();
+
+    // Get the next transaction id and increment the nonce
+    let tx_id = storage.next_tx_id.read();
+    storage.next_tx_id.write(tx_id + 1);
+
+    // Calculate the valid_until timestamp
+    let valid_until = block_timestamp() + tx_validity_duration;
+
+    // Store the transaction
+    storage.tx_ids_index.insert(tx_id, storage.tx_ids_list.len());
+    storage.tx_ids_list.push(tx_id);
+
+    // TODO: This is a workaround. We should use the calldata and function_selector from ContractCallParams directly instead of storing them in a separate storage key
+    let internal_tx_parameters = match tx_parameters {
+        TransactionParameters::Call(contract_call_params) => {
+            let calldata = storage.txs_calldata.get(tx_id);
+            calldata.write_slice(contract_call_params.calldata);
+
+            let function_selector = storage.txs_function_selector.get(tx_id);
+            function_selector.write_slice(contract_call_params.function_selector);
+
+            InternalTransactionParameters::Call(InternalContractCallParams {
+                forwarded_gas: contract_call_params.forwarded_gas,
+                transfer_params: contract_call_params.transfer_params,
+            })
+        },
+        TransactionParameters::Transfer(transfer_params) => {
+            InternalTransactionParameters::Transfer(transfer_params)
+        },
+        TransactionParameters::Batch(operations) => {
+            let operations_count = operations.len();
+
+            // Check that the batch is not empty, otherwise revert
+            require(operations_count > 0, MultisigError::EmptyBatch);
+
+            let mut i = 0;
+            while i < operations_count {
+                let operation = operations.get(i).unwrap();
+                let internal_parameters = match operation.parameters {
+                    OperationParameters::Call(contract_call_params) => {
+                        let calldata = storage.txs_batch_calldata.get((tx_id, i));
+                        calldata.write_slice(contract_call_params.calldata);
+
+                        let function_selector = storage.txs_batch_function_selector.get((tx_id, i));
+                        function_selector.write_slice(contract_call_params.function_selector);
+
+                        InternalOperationParameters::Call(InternalContractCallParams {
+                            forwarded_gas: contract_call_params.forwarded_gas,
+                            transfer_params: contract_call_params.transfer_params,
+                        })
+                    },
+                    OperationParameters::Transfer(transfer_params) => {
+                        InternalOperationParameters::Transfer(transfer_params)
+                    },
+                };
+
+                storage.txs_batch_operations.get(tx_id).push(InternalBatchOperation {
+                    to: operation.to,
+                    parameters: internal_parameters,
+                });
+
+                i += 1;
+            }
+
+            InternalTransactionParameters::Batch(operations_count)
+        },
+    };
+
+    storage
+        .txs
+        .insert(
+            tx_id,
+            Transaction {
+                tx_id,
+                proposer: caller,
+                to,
+                valid_until,
+                tx_parameters: internal_tx_parameters,
+                config_epoch: storage.config_epoch.read(),
+                gas_refund,
+            },
+        );
+
+    // The proposer approves the transaction
+    let caller_weight = get_owner_weight(caller);
+    cast_vote(tx_id, caller, true);
+
+    // Emit event
+    log(TransactionProposed {
+        tx_id: tx_id,
+        proposer: caller,
+        to: to,
+        valid_until: valid_until,
+        transaction_parameters: tx_parameters,
+        config_epoch: storage.config_epoch.read(),
+        gas_refund: gas_refund,
+        approvals_count: caller_weight,
+    });
+
+    // Start the execution delay if the proposer approval already reaches the threshold
+    queue_tx_if_threshold_reached(tx_id);
+
+    tx_id
+}
+
+#[storage(read, write)]
+fn _approve_tx(tx_id: TxId, owner: Identity) {
+    // Check if the owner has already voted, otherwise revert
+    check_if_already_voted(tx_id, owner);
+
+    let weight = get_owner_weight(owner);
+    cast_vote(tx_id, owner, true);
+    let (approvals_count, rejections_count) = get_votes_count(tx_id);
+
+    // Emit event
+    log(TransactionApproved {
+        tx_id: tx_id,
+        owner: owner,
+        weight: weight,
+        approvals_count: approvals_count,
+        rejections_count: rejections_count,
+    });
+
+    // Start the execution delay if this approval reaches the threshold
+    queue_tx_if_threshold_reached(tx_id);
+}
+
+#[storage(read, write)]
+fn _reject_tx(tx_id: TxId, owner: Identity) {
+    // Check if the owner has already voted, otherwise revert
+    check_if_already_voted(tx_id, owner);
+
+    let weight = get_owner_weight(owner);
+    cast_vote(tx_id, owner, false);
+    let (approvals_count, rejections_count) = get_votes_count(tx_id);
+
+    // Emit event
+    log(TransactionRejected {
+        tx_id: tx_id,
+        owner: owner,
+        weight: weight,
+        approvals_count: approvals_count,
+        rejections_count: rejections_count,
+    });
+
+    // Cancel the transaction if enough owners have rejected it
+    if !check_if_threshold_can_be_reached(tx_id) {
+        _remove_tx(tx_id, TransactionStatus::Cancelled);
+
+        // Emit event
+        log(TransactionCancelled {
+            tx_id: tx_id,
+            reason: CancellationReason::RejectedByOwners,
+        });
+    }
+}
+
+/// Removes the vote of an owner and returns whether it was an approval.
+#[storage(read, write)]
+fn _revoke_vote(tx_id: TxId, owner: Identity) -> bool {
+    // Check if the owner has voted, otherwise revert
+    let vote = get_vote(tx_id, owner);
+    require(vote.is_some(), MultisigError::NotVoted);
+    let was_approval = vote.unwrap();
+
+    let weight = get_owner_weight(owner);
+    let _ = storage.approvals.get(tx_id).remove(owner);
+    let _ = storage.vote_nonces.get(tx_id).remove(owner);
+    let (approvals_count, rejections_count) = get_votes_count(tx_id);
+
+    // Invalidate the signatures collected for the transaction, so the revoked vote can't be restored with them
+    storage.txs_signature_nonce.insert(tx_id, get_signature_nonce(tx_id) + 1);
+
+    // Stop the execution delay if the threshold is no longer reached
+    if was_approval && approvals_count < storage.threshold.read() {
+        let _ = storage.txs_queued_at.remove(tx_id);
+    }
+
+    // Emit event
+    log(VoteRevoked {
+        tx_id: tx_id,
+        owner: owner,
+        was_approval: was_approval,
+        weight: weight,
+        approvals_count: approvals_count,
+        rejections_count: rejections_count,
+    });
+
+    was_approval
+}
+
+/// Records the vote of an owner along with its current ownership nonce.
+#[storage(read, write)]
+fn cast_vote(tx_id: TxId, owner: Identity, approve: bool) {
+    storage.approvals.get(tx_id).insert(owner, approve);
+    storage.vote_nonces.get(tx_id).insert(owner, get_ownership_nonce(owner));
+}
+
+/// Invalidates the votes of an owner on all the pending transactions without iterating over them.
+#[storage(read, write)]
+fn invalidate_votes(owner: Identity) {
+    storage.ownership_nonces.insert(owner, get_ownership_nonce(owner) + 1);
+}
+
+#[storage(read, write)]
+fn queue_tx_if_threshold_reached(tx_id: TxId) {
+    // A transaction queued before the last configuration change is queued again, as the threshold may have been raised
+    let queued_at = storage.txs_queued_at.get(tx_id).try_read();
+    if queued_at.is_some() && queued_at.unwrap() >= storage.config_changed_at.read() {
+        return;
+    }
+
+    let (approvals_count, _) = get_votes_count(tx_id);
+    if approvals_count >= storage.threshold.read() {
+        let queued_at = block_timestamp();
+        storage.txs_queued_at.insert(tx_id, queued_at);
+
+        // Emit event
+        log(TransactionQueued {
+            tx_id: tx_id,
+            queued_at: queued_at,
+            executable_at: queued_at + storage.execution_delay.read(),
+        });
+    }
+}
+
+#[storage(read, write)]
+fn _remove_tx(tx_id: TxId, status: TransactionStatus) {
+    let finalized_by = match msg_sender() {
+        Ok(caller) => caller,
+        Err(_) => revert(0),
+    };
+
+    // Archive the final state of the transaction
+    let (approvals_count, rejections_count) = get_votes_count(tx_id);
+    storage.txs_history.insert(tx_id, TransactionRecord {
+        tx_id,
+        status,
+        finalized_at: block_timestamp(),
+        finalized_by,
+        approvals_count,
+        rejections_count,
+    });
+    storage.history_tx_ids.push(tx_id);
+
+    // Remove the transaction from active transactions, moving the last one to its position
+    let index = storage.tx_ids_index.get(tx_id).read();
+    let _ = storage.tx_ids_list.swap_remove(index);
+    if index < storage.tx_ids_list.len() {
+        let moved_tx_id = storage.tx_ids_list.get(index).unwrap().read();
+        storage.tx_ids_index.insert(moved_tx_id, index);
+    }
+    let _ = storage.tx_ids_index.remove(tx_id);
+
+    // Remove the calldata and function selector of every operation of a batch transaction
+    if let InternalTransactionParameters::Batch(operations_count) = storage.txs.get(tx_id).read().tx_parameters {
+        let mut i = 0;
+        while i < operations_count {
+            let _ = storage.txs_batch_calldata.remove((tx_id, i));
+            let _ = storage.txs_batch_function_selector.remove((tx_id, i));
+            i += 1;
+        }
+    }
+
+    let _ = storage.txs.remove(tx_id);
+    let _ = storage.txs_calldata.remove(tx_id);
+    let _ = storage.txs_function_selector.remove(tx_id);
+    let _ = storage.txs_batch_operations.remove(tx_id);
+    let _ = storage.approvals.remove(tx_id);
+    let _ = storage.vote_nonces.remove(tx_id);
+    let _ = storage.txs_signature_nonce.remove(tx_id);
+    let _ = storage.txs_queued_at.remove(tx_id);
+
+    // Emit event
+    log(TransactionRemoved {
+        tx_id: tx_id,
+        status: status,
+        finalized_by: finalized_by,
+    });
+}
+
+#[storage(read, write)]
+fn bump_config_epoch() -> u64 {
+    let config_epoch = storage.config_epoch.read() + 1;
+    storage.config_epoch.write(config_epoch);
+    storage.config_changed_at.write(block_timestamp());
+    config_epoch
+}
+
+#[storage(read, write)]
+fn _add_owner(owner: Identity, weight: Weight) {
+    // Check that the owner is not already in the list, otherwise revert
+    let owner_exists = storage.owners.get(owner).try_read();
+    require(owner_exists.is_none(), MultisigError::AlreadyOwner);
+
+    // Check that the weight is not 0, otherwise revert
+    require(weight != 0, MultisigError::WeightCannotBeZero);
+
+    // Add the owner with all the roles
+    storage.owners.insert(owner, weight);
+    storage.owners_list.push(owner);
+    storage.roles.insert(owner, Roles::all());
+    storage.total_weight.write(storage.total_weight.read() + weight);
+}
+
+#[storage(read, write)]
+fn _remove_owner(owner: Identity) {
+    // Invalidate the votes of the owner on the pending transactions, so they can't be executed with them
+    invalidate_votes(owner);
+
+    // Subtract the weight of the owner from the total weight
+    let total_weight = storage.total_weight.read() - get_owner_weight(owner);
+    storage.total_weight.write(total_weight);
+
+    // Remove the owner from the mapping and revoke its roles
+    let _ = storage.owners.remove(owner);
+    let _ = storage.roles.remove(owner);
+
+    // Remove the owner from the list
+    let owners_list = storage.owners_list.load_vec();
+    let mut i = 0;
+    while i < owners_list.len() {
+        if owners_list.get(i).unwrap() == owner {
+            let _ = storage.owners_list.remove(i);
+            break;
+        }
+        i += 1;
     }
 }
 
 #[storage(read)]
 fn _execute_tx(transaction: Transaction) {
-    // Check if it is a call or a transfer and execute it.
-    match transaction.tx_parameters {
-        InternalTransactionParameters::Call(contract_call_params) => {
-            let target_contract_id = match transaction.to {
-                Identity::ContractId(contract_identifier) => contract_identifier,
-                _ => {
-                    require(false, MultisigError::CanOnlyCallContracts);
-                    revert(0); //TODO: This was added to make the compiler happy. This code is unreachable.
-                },
-            };
+    _execute_parameters(transaction.to, get_tx_parameters(transaction));
+}
 
-            if contract_call_params.transfer_params.value.is_some() {
-                require(
-                    contract_call_params
-                        .transfer_params
-                        .value
-                        .unwrap() <= this_balance(contract_call_params.transfer_params.asset_id),
-                    MultisigError::InsufficientAssetAmount,
-                );
+fn _execute_parameters(to: Identity, tx_parameters: TransactionParameters) {
+    // Check if it is a call, a transfer or a batch and execute it.
+    match tx_parameters {
+        TransactionParameters::Call(contract_call_params) => {
+            _execute_call(to, contract_call_params);
+        },
+        TransactionParameters::Transfer(transfer_params) => {
+            _execute_transfer(to, transfer_params);
+        },
+        TransactionParameters::Batch(operations) => {
+            // The operations are executed in order. If any of them reverts, the whole batch reverts.
+            let mut i = 0;
+            while i < operations.len() {
+                let operation = operations.get(i).unwrap();
+
+                match operation.parameters {
+                    OperationParameters::Call(contract_call_params) => {
+                        _execute_call(operation.to, contract_call_params);
+                    },
+                    OperationParameters::Transfer(transfer_params) => {
+                        _execute_transfer(operation.to, transfer_params);
+                    },
+                }
+
+                i += 1;
             }
+        },
+    }
+}
 
-            let call_params = CallParams {
-                coins: contract_call_params.transfer_params.value.unwrap_or(0),
-                asset_id: contract_call_params.transfer_params.asset_id,
-                gas: contract_call_params.forwarded_gas,
-            };
+fn _execute_call(to: Identity, contract_call_params: ContractCallParams) {
+    let target_contract_id = match to {
+        Identity::ContractId(contract_identifier) => contract_identifier,
+        _ => {
+            require(false, MultisigError::CanOnlyCallContracts);
+            revert(0); //TODO: This was added to make the compiler happy. This code is unreachable.
+        },
+    };
 
-            // TODO: This is a workaround. We should use the calldata and function_selector from ContractCallParams directly instead of storing them in a separate storage key
-            let function_selector = storage.txs_function_selector.get(transaction.tx_id).read_slice().unwrap();
-            let calldata = storage.txs_calldata.get(transaction.tx_id).read_slice().unwrap();
+    if contract_call_params.transfer_params.value.is_some() {
+        require(
+            contract_call_params
+                .transfer_params
+                .value
+                .unwrap() <= this_balance(contract_call_params.transfer_params.asset_id),
+            MultisigError::InsufficientAssetAmount,
+        );
+    }
 
-            call_with_function_selector(
-                target_contract_id,
-                function_selector,
-                calldata,
-                call_params,
-            );
+    let call_params = CallParams {
+        coins: contract_call_params.transfer_params.value.unwrap_or(0),
+        asset_id: contract_call_params.transfer_params.asset_id,
+        gas: contract_call_params.forwarded_gas,
+    };
+
+    call_with_function_selector(
+        target_contract_id,
+        contract_call_params.function_selector,
+        contract_call_params.calldata,
+        call_params,
+    );
+}
+
+fn _execute_transfer(to: Identity, transfer_params: TransferParams) {
+    require(
+        transfer_params
+            .value
+            .is_some(),
+        MultisigError::TransferRequiresAValue,
+    );
+    let value = transfer_params.value.unwrap();
+    require(
+        value <= this_balance(transfer_params.asset_id),
+        MultisigError::InsufficientAssetAmount,
+    );
+
+    transfer(to, transfer_params.asset_id, value);
+}
+
+fn _refund_gas(tx_id: TxId, gas_refund: GasRefund, gas_used: u64) {
+    let executor = match msg_sender() {
+        Ok(caller) => caller,
+        Err(_) => revert(0),
+    };
+
+    // The refund is limited by the maximum amount and by the balance of the contract
+    let mut amount = if gas_refund.gas_price != 0 && gas_used > gas_refund.max_amount / gas_refund.gas_price {
+        gas_refund.max_amount
+    } else {
+        gas_used * gas_refund.gas_price
+    };
+    let balance = this_balance(gas_refund.asset_id);
+    if amount > balance {
+        amount = balance;
+    }
+
+    if amount == 0 {
+        return;
+    }
+
+    transfer(executor, gas_refund.asset_id, amount);
+
+    // Emit event
+    log(GasRefunded {
+        tx_id: tx_id,
+        executor: executor,
+        asset_id: gas_refund.asset_id,
+        amount: amount,
+    });
+}
+
+/// Checks that the transaction does not call an identity that is not a contract nor transfer without a value,
+/// as it could never be executed. Reverts inside the called contracts can not be caught by the FuelVM, so a
+/// transaction whose call reverts stays pending until it is cancelled or expires.
+fn check_tx_parameters(to: Identity, tx_parameters: TransactionParameters) {
+    match tx_parameters {
+        TransactionParameters::Call(_) => check_call_target(to),
+        TransactionParameters::Transfer(transfer_params) => check_transfer_value(transfer_params),
+        TransactionParameters::Batch(operations) => {
+            let mut i = 0;
+            while i < operations.len() {
+                let operation = operations.get(i).unwrap();
+                match operation.parameters {
+                    OperationParameters::Call(_) => check_call_target(operation.to),
+                    OperationParameters::Transfer(transfer_params) => check_transfer_value(transfer_params),
+                };
+
+                i += 1;
+            }
+        },
+    }
+}
+
+fn check_call_target(to: Identity) {
+    match to {
+        Identity::ContractId(_) => {},
+        _ => require(false, MultisigError::CanOnlyCallContracts),
+    };
+}
+
+fn check_transfer_value(transfer_params: TransferParams) {
+    require(transfer_params.value.is_some(), MultisigError::TransferRequiresAValue);
+}
+
+/// Returns whether the transaction is a self call to `set_guard`.
+#[storage(read)]
+fn is_guard_change(tx: Transaction) -> bool {
+    match tx.tx_parameters {
+        InternalTransactionParameters::Call(_) => {
+            tx.to == Identity::ContractId(ContractId::this())
+                && storage.txs_function_selector.get(tx.tx_id).read_slice().unwrap() == Bytes::from(encode("set_guard"))
+        },
+        _ => false,
+    }
+}
+
+#[storage(read)]
+fn get_tx_data(tx_id: TxId) -> Option<TransactionData> {
+    let tx = storage.txs.get(tx_id).try_read();
+
+    if let Some(tx) = tx {
+        let (approvals_count, rejections_count) = get_votes_count(tx_id);
+        Some(TransactionData{
+            tx_id: tx_id,
+            proposer: tx.proposer,
+            to: tx.to,
+            valid_until: tx.valid_until,
+            config_epoch: tx.config_epoch,
+            gas_refund: tx.gas_refund,
+            tx_parameters: get_tx_parameters(tx),
+            approvals_count,
+            rejections_count,
+        })
+    }
+    else {
+        return None;
+    }
+}
+
+/// Builds the data passed to the guard for a module execution, which is not proposed nor voted, so it has no tx id nor votes.
+#[storage(read)]
+fn get_module_tx_data(module: ContractId, to: Identity, tx_parameters: TransactionParameters) -> TransactionData {
+    TransactionData {
+        tx_id: 0,
+        proposer: Identity::ContractId(module),
+        to: to,
+        valid_until: block_timestamp(),
+        config_epoch: storage.config_epoch.read(),
+        gas_refund: None,
+        tx_parameters: tx_parameters,
+        approvals_count: 0,
+        rejections_count: 0,
+    }
+}
+
+#[storage(read)]
+fn get_tx_parameters(tx: Transaction) -> TransactionParameters {
+    match tx.tx_parameters {
+        InternalTransactionParameters::Call(contract_call_params) => {
+            TransactionParameters::Call(ContractCallParams {
+                calldata: storage.txs_calldata.get(tx.tx_id).read_slice().unwrap(),
+                forwarded_gas: contract_call_params.forwarded_gas,
+                function_selector: storage.txs_function_selector.get(tx.tx_id).read_slice().unwrap(),
+                transfer_params: contract_call_params.transfer_params,
+            })
         },
         InternalTransactionParameters::Transfer(transfer_params) => {
-            require(
-                transfer_params
-                    .value
-                    .is_some(),
-                MultisigError::TransferRequiresAValue,
-            );
-            let value = transfer_params.value.unwrap();
-            require(
-                value <= this_balance(transfer_params.asset_id),
-                MultisigError::InsufficientAssetAmount,
-            );
+            TransactionParameters::Transfer(transfer_params)
+        },
+        InternalTransactionParameters::Batch(operations_count) => {
+            TransactionParameters::Batch(get_batch_operations(tx.tx_id, operations_count))
+        },
+    }
+}
 
-            transfer(transaction.to, transfer_params.asset_id, value);
+/// Computes the hash signed by the owners to approve a transaction off-chain.
+/// It includes the current config epoch and the signature nonce of the transaction, so the signatures
+/// can't be replayed after a vote is revoked or the owners change.
+#[storage(read)]
+fn compute_tx_hash(tx: Transaction) -> b256 {
+    let mut hasher = Hasher::new();
+    ContractId::this().hash(hasher);
+    tx.tx_id.hash(hasher);
+    storage.config_epoch.read().hash(hasher);
+    get_signature_nonce(tx.tx_id).hash(hasher);
+    tx.to.hash(hasher);
+    tx.valid_until.hash(hasher);
+    match tx.gas_refund {
+        Some(gas_refund) => {
+            1_u8.hash(hasher);
+            gas_refund.hash(hasher);
+        },
+        None => {
+            0_u8.hash(hasher);
         },
     }
+    get_tx_parameters(tx).hash(hasher);
+    hasher.sha256()
 }
 
 #[storage(read)]
-fn get_caller_if_owner() -> Identity {
-    let caller = match msg_sender() {
-        Ok(caller) => caller,
-        Err(_) => revert(0),
-    };
+fn get_batch_operations(tx_id: TxId, operations_count: u64) -> Vec<BatchOperation> {
+    let mut operations = Vec::new();
+
+    let mut i = 0;
+    while i < operations_count {
+        let operation = storage.txs_batch_operations.get(tx_id).get(i).unwrap().read();
+
+        let parameters = match operation.parameters {
+            InternalOperationParameters::Call(contract_call_params) => {
+                OperationParameters::Call(ContractCallParams {
+                    calldata: storage.txs_batch_calldata.get((tx_id, i)).read_slice().unwrap(),
+                    forwarded_gas: contract_call_params.forwarded_gas,
+                    function_selector: storage.txs_batch_function_selector.get((tx_id, i)).read_slice().unwrap(),
+                    transfer_params: contract_call_params.transfer_params,
+                })
+            },
+            InternalOperationParameters::Transfer(transfer_params) => {
+                OperationParameters::Transfer(transfer_params)
+            },
+        };
+
+        operations.push(BatchOperation {
+            to: operation.to,
+            parameters,
+        });
+
+        i += 1;
+    }
+
+    operations
+}
+
+#[storage(read, write)]
+fn _approve_recovery(guardian: Identity) {
+    // Check that there is a recovery in progress, otherwise revert
+    let recovery = storage.recovery.read();
+    require(recovery.is_some(), MultisigError::NoRecoveryInProgress);
+    let mut recovery = recovery.unwrap();
 
-    // Check if the caller is an owner, otherwise revert
+    // Check that the guardian has not approved the recovery yet, otherwise revert
     require(
         storage
-            .owners
-            .get(caller)
+            .recovery_approvals
+            .get((recovery.recovery_id, guardian))
             .try_read()
-            .is_some(),
-        MultisigError::NotOwner,
+            .is_none(),
+        MultisigError::AlreadyVoted,
     );
+    storage.recovery_approvals.insert((recovery.recovery_id, guardian), true);
+
+    // Start the recovery delay when the guardian threshold is reached
+    recovery.approvals += 1;
+    if recovery.approved_at.is_none() && recovery.approvals >= storage.guardian_threshold.read() {
+        recovery.approved_at = Some(block_timestamp());
+    }
+    storage.recovery.write(Some(recovery));
+
+    // Emit event
+    log(RecoveryApproved {
+        recovery_id: recovery.recovery_id,
+        guardian: guardian,
+        approvals: recovery.approvals,
+        approved_at: recovery.approved_at,
+    });
+}
+
+#[storage(read, write)]
+fn _cancel_recovery(cancelled_by: Identity) {
+    let recovery = storage.recovery.read();
+    if let Some(recovery) = recovery {
+        storage.recovery.write(None);
+        storage.recovery_owners.clear();
+
+        // Emit event
+        log(RecoveryCancelled {
+            recovery_id: recovery.recovery_id,
+            cancelled_by: cancelled_by,
+        });
+    }
+}
+
+fn check_owners_config(owners: Vec<Owner>, threshold: Weight) {
+    // Check that the threshold is not 0, otherwise revert
+    require(threshold != 0, MultisigError::ThresholdCannotBeZero);
+
+    // Check that the owners list is not empty and does not exceed the limit, otherwise revert
+    let owners_count = owners.len();
+    require(owners_count > 0, MultisigError::OwnersCannotBeEmpty);
+    require(owners_count <= MAX_OWNERS.as_u64(), MultisigError::MaxOwnersReached);
+
+    let mut total_weight = 0;
+    let mut i = 0;
+    while i < owners_count {
+        let owner = owners.get(i).unwrap();
+
+        // Check that the weight is not 0, otherwise revert
+        require(owner.weight != 0, MultisigError::WeightCannotBeZero);
+
+        // Check that the owner is not duplicated, otherwise revert
+        let mut j = 0;
+        while j < i {
+            require(owners.get(j).unwrap().identity != owner.identity, MultisigError::DuplicatedOwner);
+            j += 1;
+        }
+
+        total_weight += owner.weight;
+        i += 1;
+    }
+
+    // Check that the threshold is not greater than the total weight, otherwise revert
+    require(total_weight >= threshold, MultisigError::ThresholdCannotBeGreaterThanOwners);
+}
+
+#[storage(read)]
+fn get_guardian() -> Identity {
+    let caller = match msg_sender() {
+        Ok(caller) => caller,
+        Err(_) => revert(0),
+    };
+
+    // Check that the caller is a guardian, otherwise revert
+    require(storage.guardians.get(caller).try_read().is_some(), MultisigError::NotGuardian);
 
     caller
 }
 
+#[storage(read, write)]
+fn record_owner_activity() {
+    let caller = match msg_sender() {
+        Ok(caller) => caller,
+        Err(_) => revert(0),
+    };
+
+    if storage.owners.get(caller).try_read().is_some() {
+        storage.last_activity.write(block_timestamp());
+    }
+}
+
+#[storage(read)]
+fn get_caller_roles() -> (Identity, Roles) {
+    let caller = match msg_sender() {
+        Ok(caller) => caller,
+        Err(_) => revert(0),
+    };
+
+    // Check if the caller is an owner or has been granted any role, otherwise revert
+    let roles = storage.roles.get(caller).try_read();
+    require(roles.is_some(), MultisigError::NotOwner);
+
+    (caller, roles.unwrap())
+}
+
+#[storage(read)]
+fn get_signature_nonce(tx_id: TxId) -> u64 {
+    storage.txs_signature_nonce.get(tx_id).try_read().unwrap_or(0)
+}
+
+#[storage(read)]
+fn get_ownership_nonce(identity: Identity) -> u64 {
+    storage.ownership_nonces.get(identity).try_read().unwrap_or(0)
+}
+
+/// Returns the vote of an identity on a transaction, ignoring the votes cast before it was removed as owner.
+#[storage(read)]
+fn get_vote(tx_id: TxId, identity: Identity) -> Option<bool> {
+    let vote = storage.approvals.get(tx_id).get(identity).try_read();
+    let vote_nonce = storage.vote_nonces.get(tx_id).get(identity).try_read().unwrap_or(0);
+    if vote.is_some() && vote_nonce == get_ownership_nonce(identity) {
+        vote
+    } else {
+        None
+    }
+}
+
+/// Returns the weight of the approvals and rejections of the current owners on a transaction, counting each vote
+/// with the current weight of its owner.
+/// Iterating over the owners keeps the cost bounded by MAX_OWNERS, whatever the number of pending transactions.
+#[storage(read)]
+fn get_votes_count(tx_id: TxId) -> (Approvals, Rejections) {
+    let owners_list = storage.owners_list.load_vec();
+    let mut approvals_count = 0;
+    let mut rejections_count = 0;
+
+    let mut i = 0;
+    while i < owners_list.len() {
+        let owner = owners_list.get(i).unwrap();
+        match get_vote(tx_id, owner) {
+            Some(true) => approvals_count += get_owner_weight(owner),
+            Some(false) => rejections_count += get_owner_weight(owner),
+            None => {},
+        }
+        i += 1;
+    }
+
+    (approvals_count, rejections_count)
+}
+
+#[storage(read)]
+fn get_owner_weight(owner: Identity) -> Weight {
+    storage.owners.get(owner).try_read().unwrap_or(0)
+}
+
 #[storage(read)]
 fn check_tx_id_validity(tx_id: TxId) {
     require(
@@ -574,15 +2278,7 @@ fn check_tx_id_validity(tx_id: TxId) {
 #[storage(read)]
 fn check_if_already_voted(tx_id: TxId, owner: Identity) {
     // TxId is not checked here because it is already checked in the approve_tx and reject_tx functions
-    require(
-        storage
-            .approvals
-            .get(tx_id)
-            .get(owner)
-            .try_read()
-            .is_none(),
-        MultisigError::AlreadyVoted,
-    );
+    require(get_vote(tx_id, owner).is_none(), MultisigError::AlreadyVoted);
 }
 
 #[storage(read)]
@@ -591,14 +2287,69 @@ fn check_if_tx_expired(tx_id: TxId) -> bool {
     block_timestamp() > tx.valid_until
 }
 
+#[storage(read)]
+fn check_timelock(tx_id: TxId) {
+    let execution_delay = storage.execution_delay.read();
+    if execution_delay == 0 {
+        return;
+    }
+
+    // Transactions that have not been queued can not be executed while there is an execution delay
+    let queued_at = get_queued_at(tx_id);
+    require(
+        queued_at.is_some() && block_timestamp() >= queued_at.unwrap() + execution_delay,
+        MultisigError::TimelockNotExpired,
+    );
+}
+
+#[storage(read)]
+fn get_queued_at(tx_id: TxId) -> Option<u64> {
+    // A transaction is only queued while its approvals reach the current threshold
+    let (approvals_count, _) = get_votes_count(tx_id);
+    if approvals_count < storage.threshold.read() {
+        return None;
+    }
+
+    // A transaction that reached the threshold through a configuration change is queued since that change
+    let config_changed_at = storage.config_changed_at.read();
+    let queued_at = storage.txs_queued_at.get(tx_id).try_read().unwrap_or(0);
+    if queued_at > config_changed_at {
+        Some(queued_at)
+    } else {
+        Some(config_changed_at)
+    }
+}
+
 #[storage(read)]
 fn check_if_threshold_can_be_reached(tx_id: TxId) -> bool {
     let threshold = storage.threshold.read();
-    let owners_count = storage.owners_list.len();
-    let rejections_count = storage.rejections_count.get(tx_id).read();
+    let total_weight = storage.total_weight.read();
+    let (_, rejections_count) = get_votes_count(tx_id);
 
-    // If the rejections are greater than the owners - threshold, the threshold can't be reached
-    rejections_count.as_u64() < (owners_count - threshold.as_u64())
+    // If the weight of the owners that have not rejected is lower than the threshold, it can't be reached
+    rejections_count + threshold <= total_weight
+}
+
+#[storage(read)]
+fn check_executor_policy() {
+    match storage.executor_policy.read() {
+        ExecutorPolicy::Anyone => {},
+        ExecutorPolicy::OwnersOnly => {
+            let caller = match msg_sender() {
+                Ok(caller) => caller,
+                Err(_) => revert(0),
+            };
+            require(storage.owners.get(caller).try_read().is_some(), MultisigError::NotExecutor);
+        },
+        ExecutorPolicy::Allowlist => {
+            let caller = match msg_sender() {
+                Ok(caller) => caller,
+                Err(_) => revert(0),
+            };
+            let roles = storage.roles.get(caller).try_read();
+            require(roles.is_some() && roles.unwrap().executor, MultisigError::NotExecutor);
+        },
+    }
 }
 
 fn check_self_call() {
@@ -3,19 +3,107 @@ library;
 use std::storage::storage_bytes::*;
 use std::bytes::Bytes;
 use std::asset_id::AssetId;
+use std::hash::{Hash, Hasher};
 
 pub type TxId = u256;
-pub type Approvals = u8;
-pub type Rejections = u8;
+pub type Weight = u64;
+pub type Approvals = Weight;
+pub type Rejections = Weight;
 
 pub const MAX_OWNERS: u8 = 10;
-pub const MAX_TRANSACTIONS: u8 = 10;
+
+/// An owner of the multisig wallet and the weight of its votes.
+pub struct Owner {
+    pub identity: Identity,
+    pub weight: Weight,
+}
+
+/// The actions an identity is allowed to perform on the multisig wallet.
+pub struct Roles {
+    /// Can propose transactions.
+    pub proposer: bool,
+    /// Can approve transactions.
+    pub approver: bool,
+    /// Can reject transactions.
+    pub rejecter: bool,
+    /// Can execute approved transactions.
+    pub executor: bool,
+}
+
+impl Roles {
+    /// The roles granted to every owner by default.
+    pub fn all() -> Self {
+        Self {
+            proposer: true,
+            approver: true,
+            rejecter: true,
+            executor: true,
+        }
+    }
+}
+
+/// The reimbursement of the gas spent by the executor of a transaction.
+pub struct GasRefund {
+    /// The asset the refund is paid in.
+    pub asset_id: AssetId,
+    /// The amount of the asset paid per unit of gas consumed by the execution.
+    pub gas_price: u64,
+    /// The maximum amount of the asset that is refunded.
+    pub max_amount: u64,
+}
+
+/// Determines who can execute an approved transaction.
+pub enum ExecutorPolicy {
+    /// Any identity can execute.
+    Anyone: (),
+    /// Only the owners can execute.
+    OwnersOnly: (),
+    /// Only the identities with the executor role can execute.
+    Allowlist: (),
+}
+
+/// An amount of an asset that a delegate can spend without a proposal.
+pub struct Allowance {
+    /// The maximum amount that can be spent in a period.
+    pub amount: u64,
+    /// The duration of a period in seconds. If 0, the allowance never resets.
+    pub reset_period: u64,
+    /// The amount spent in the current period.
+    pub spent: u64,
+    /// The timestamp of the start of the current period.
+    pub last_reset: u64,
+}
+
+/// A recovery of the multisig wallet started by the guardians.
+pub struct Recovery {
+    pub recovery_id: u64,
+    /// The threshold set when the recovery is finalized.
+    pub new_threshold: Weight,
+    /// The number of guardians that approved the recovery.
+    pub approvals: u64,
+    /// The timestamp when the guardian threshold was reached, which starts the recovery delay.
+    pub approved_at: Option<u64>,
+}
+
+/// The full data of a recovery.
+pub struct RecoveryData {
+    pub recovery_id: u64,
+    pub new_owners: Vec<Owner>,
+    pub new_threshold: Weight,
+    pub approvals: u64,
+    pub approved_at: Option<u64>,
+}
 
 /// The transaction that is being proposed.
 pub struct Transaction {
     pub tx_id: TxId,
+    pub proposer: Identity,
     pub to: Identity,
     pub valid_until: u64,
+    /// The configuration epoch when the transaction was proposed.
+    pub config_epoch: u64,
+    /// The gas refund paid to the executor, if any.
+    pub gas_refund: Option<GasRefund>,
     pub tx_parameters: InternalTransactionParameters,
 }
 
@@ -23,11 +111,38 @@ pub struct Transaction {
 pub enum TransactionParameters {
     Call: ContractCallParams,
     Transfer: TransferParams,
+    Batch: Vec<BatchOperation>,
 }
 
 pub enum InternalTransactionParameters {
     Call: InternalContractCallParams,
     Transfer: TransferParams,
+    /// The number of operations in the batch.
+    Batch: u64,
+}
+
+/// A single operation of a batch transaction.
+pub struct BatchOperation {
+    /// The recipient of the operation.
+    pub to: Identity,
+    /// The parameters of the operation.
+    pub parameters: OperationParameters,
+}
+
+/// Determines the type of a batched operation.
+pub enum OperationParameters {
+    Call: ContractCallParams,
+    Transfer: TransferParams,
+}
+
+pub struct InternalBatchOperation {
+    pub to: Identity,
+    pub parameters: InternalOperationParameters,
+}
+
+pub enum InternalOperationParameters {
+    Call: InternalContractCallParams,
+    Transfer: TransferParams,
 }
 
 /// Parameters for calling a contract.
@@ -58,12 +173,136 @@ pub struct TransferParams {
     pub value: Option<u64>,
 }
 
+/// The reason a pending transaction was cancelled.
+pub enum CancellationReason {
+    /// The proposer cancelled the transaction.
+    CancelledByProposer: (),
+    /// The owners rejected the transaction until the threshold could no longer be reached.
+    RejectedByOwners: (),
+    /// The transaction was removed after it expired.
+    Expired: (),
+    /// The transaction was removed because its threshold could no longer be reached.
+    ThresholdUnreachable: (),
+}
+
+/// The status of a transaction.
+pub enum TransactionStatus {
+    /// The transaction is waiting for votes or execution.
+    Pending: (),
+    /// The transaction was executed.
+    Executed: (),
+    /// The transaction was cancelled by its proposer or rejected by the owners.
+    Cancelled: (),
+    /// The transaction was removed after it expired.
+    Expired: (),
+}
+
+/// The archived record of a transaction that is no longer active.
+pub struct TransactionRecord {
+    pub tx_id: TxId,
+    /// The final status of the transaction.
+    pub status: TransactionStatus,
+    /// The timestamp at which the transaction was finalized.
+    pub finalized_at: u64,
+    /// The identity that executed, cancelled or removed the transaction.
+    pub finalized_by: Identity,
+    /// The final weight of the approvals.
+    pub approvals_count: Approvals,
+    /// The final weight of the rejections.
+    pub rejections_count: Rejections,
+}
+
 /// The full data of a transaction.
 pub struct TransactionData {
     pub tx_id: TxId,
+    pub proposer: Identity,
     pub to: Identity,
     pub valid_until: u64,
+    pub config_epoch: u64,
+    pub gas_refund: Option<GasRefund>,
     pub tx_parameters: TransactionParameters,
-    pub approvals_count: u8,
-    pub rejections_count: u8
+    pub approvals_count: Approvals,
+    pub rejections_count: Rejections
+}
+
+// The hashing of the transaction parameters defines the canonical encoding signed by the owners
+// when approving a transaction off-chain. Variable length fields are prefixed with their length.
+impl Hash for TransferParams {
+    fn hash(self, ref mut state: Hasher) {
+        self.asset_id.hash(state);
+        match self.value {
+            Some(value) => {
+                1_u8.hash(state);
+                value.hash(state);
+            },
+            None => {
+                0_u8.hash(state);
+            },
+        }
+    }
+}
+
+impl Hash for GasRefund {
+    fn hash(self, ref mut state: Hasher) {
+        self.asset_id.hash(state);
+        self.gas_price.hash(state);
+        self.max_amount.hash(state);
+    }
+}
+
+impl Hash for ContractCallParams {
+    fn hash(self, ref mut state: Hasher) {
+        self.calldata.len().hash(state);
+        self.calldata.hash(state);
+        self.forwarded_gas.hash(state);
+        self.function_selector.len().hash(state);
+        self.function_selector.hash(state);
+        self.transfer_params.hash(state);
+    }
+}
+
+impl Hash for OperationParameters {
+    fn hash(self, ref mut state: Hasher) {
+        match self {
+            OperationParameters::Call(contract_call_params) => {
+                0_u8.hash(state);
+                contract_call_params.hash(state);
+            },
+            OperationParameters::Transfer(transfer_params) => {
+                1_u8.hash(state);
+                transfer_params.hash(state);
+            },
+        }
+    }
+}
+
+impl Hash for BatchOperation {
+    fn hash(self, ref mut state: Hasher) {
+        self.to.hash(state);
+        self.parameters.hash(state);
+    }
+}
+
+impl Hash for TransactionParameters {
+    fn hash(self, ref mut state: Hasher) {
+        match self {
+            TransactionParameters::Call(contract_call_params) => {
+                0_u8.hash(state);
+                contract_call_params.hash(state);
+            },
+            TransactionParameters::Transfer(transfer_params) => {
+                1_u8.hash(state);
+                transfer_params.hash(state);
+            },
+            TransactionParameters::Batch(operations) => {
+                2_u8.hash(state);
+                operations.len().hash(state);
+                let mut i = 0;
+                while i < operations.len() {
+                    operations.get(i).unwrap().hash(state);
+                    i += 1;
+                }
+            },
+        }
+    }
 }
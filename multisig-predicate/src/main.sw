@@ -0,0 +1,68 @@
+predicate;
+
+use std::{
+    b512::B512,
+    ecr::ec_recover_address,
+    tx::{
+        tx_id,
+        tx_witness_data,
+        tx_witnesses_count,
+    },
+};
+
+/// Maximum number of owners of the predicate.
+const MAX_OWNERS: u64 = 10;
+
+configurable {
+    /// The number of owner signatures required to spend a coin.
+    THRESHOLD: u64 = 0,
+    /// The owners of the predicate. Unused slots must be left as the zero address.
+    OWNERS: [Address; 10] = [
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    ],
+}
+
+fn main() -> bool {
+    // A predicate without threshold could be spent by anyone
+    if THRESHOLD == 0 {
+        return false;
+    }
+
+    let tx_hash = tx_id();
+    let witnesses_count = tx_witnesses_count();
+
+    // Each owner can only be counted once
+    let mut already_signed = [false; 10];
+    let mut signatures_count = 0;
+
+    let mut i = 0;
+    while i < witnesses_count {
+        let signature = tx_witness_data::<B512>(i).unwrap();
+
+        // Witnesses that are not valid signatures are ignored
+        if let Ok(signer) = ec_recover_address(signature, tx_hash) {
+            let mut j = 0;
+            while j < MAX_OWNERS {
+                if !already_signed[j] && signer != Address::zero() && signer == OWNERS[j] {
+                    already_signed[j] = true;
+                    signatures_count += 1;
+                    break;
+                }
+                j += 1;
+            }
+        }
+
+        i += 1;
+    }
+
+    signatures_count >= THRESHOLD
+}
@@ -0,0 +1,7 @@
+library;
+
+// Errors
+pub enum ProxyError {
+    /// The target can only be changed by the multisig contract itself
+    Unauthorized: (),
+}
@@ -0,0 +1,7 @@
+library;
+
+/// Event emitted when the owners upgrade the multisig implementation
+pub struct ProxyTargetChanged{
+    pub previous_target: ContractId,
+    pub new_target: ContractId
+}
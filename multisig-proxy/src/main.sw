@@ -0,0 +1,78 @@
+contract;
+
+mod errors;
+mod events;
+
+use errors::ProxyError;
+use events::ProxyTargetChanged;
+use std::execution::run_external;
+
+configurable {
+    /// The multisig implementation used until the owners upgrade the proxy.
+    INITIAL_TARGET: ContractId = ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+}
+
+/// The SRC-14 proxy standard.
+abi SRC14 {
+    #[storage(read, write)]
+    fn set_proxy_target(new_target: ContractId);
+    #[storage(read)]
+    fn proxy_target() -> Option<ContractId>;
+}
+
+// The target is stored at sha256("storage_SRC14_0"), as specified by SRC-14, so it never collides with the
+// multisig storage, which lives in its own namespace.
+#[namespace(SRC14)]
+storage {
+    /// The multisig implementation the calls are delegated to, once it has been changed by the owners.
+    target: Option<ContractId> = None,
+}
+
+impl SRC14 for Contract {
+    #[storage(read, write)]
+    fn set_proxy_target(new_target: ContractId) {
+        // Check that the caller is the multisig itself, running behind this proxy, otherwise revert.
+        // The owners can only change the target by executing an approved self-call proposal.
+        check_self_call();
+
+        let previous_target = get_target();
+        storage.target.write(Some(new_target));
+
+        // Emit event
+        log(ProxyTargetChanged {
+            previous_target: previous_target,
+            new_target: new_target,
+        });
+    }
+
+    #[storage(read)]
+    fn proxy_target() -> Option<ContractId> {
+        Some(get_target())
+    }
+}
+
+#[fallback]
+#[storage(read)]
+fn fallback() {
+    // Run the multisig implementation in the context of the proxy, so it uses the proxy storage and balances
+    run_external(get_target())
+}
+
+// Helper functions
+#[storage(read)]
+fn get_target() -> ContractId {
+    storage.target.read().unwrap_or(INITIAL_TARGET)
+}
+
+fn check_self_call() {
+    let caller = match msg_sender() {
+        Ok(caller) => caller,
+        Err(_) => revert(0),
+    };
+
+    let is_self_call = match caller {
+        Identity::ContractId(caller_contract_id) => caller_contract_id == ContractId::this(),
+        _ => false,
+    };
+    require(is_self_call, ProxyError::Unauthorized);
+}
@@ -0,0 +1,315 @@
+use fuels::prelude::*;
+use fuels::types::Identity;
+
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{
+    advance_block_time, base_asset_contract_id, call_parameters_set_allowance, deploy_multisig,
+    get_multisig_caller, get_wallets, wallets_to_owners,
+};
+use crate::utils::validate_error;
+
+const RESET_PERIOD: u64 = 24 * 3600;
+
+#[tokio::test]
+async fn given_a_multisig_with_an_allowance_for_a_delegate_when_the_delegate_spends_it_then_the_transfers_are_performed_until_the_allowance_is_exceeded(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            deployer.contract.contract_id(),
+            DEFAULT_TRANSFER_AMOUNT * 2,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Grant an allowance that never resets to a delegate that is not an owner
+    let delegate = Identity::Address(wallets[2].address().into());
+    let allowance_amount = DEFAULT_TRANSFER_AMOUNT + DEFAULT_TRANSFER_AMOUNT / 2;
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            3600,
+            call_parameters_set_allowance(
+                delegate.clone(),
+                base_asset_contract_id(),
+                allowance_amount,
+                0,
+            ),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let _ = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // The delegate spends part of the allowance without a proposal
+    let receiver_wallet = WalletUnlocked::new_random(None);
+    let receiver = Identity::Address(receiver_wallet.address().into());
+    let delegate_caller = get_multisig_caller(&contract_id, wallets[2].clone());
+    let response = delegate_caller
+        .contract
+        .methods()
+        .spend_allowance(
+            base_asset_contract_id(),
+            receiver.clone(),
+            DEFAULT_TRANSFER_AMOUNT,
+        )
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    assert!(response.is_ok());
+
+    let allowance = deployer
+        .contract
+        .methods()
+        .get_allowance(delegate, base_asset_contract_id())
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+
+    assert_eq!(allowance.amount, allowance_amount);
+    assert_eq!(allowance.spent, DEFAULT_TRANSFER_AMOUNT);
+
+    // The delegate tries to spend more than the remaining allowance
+    let response = delegate_caller
+        .contract
+        .methods()
+        .spend_allowance(base_asset_contract_id(), receiver, DEFAULT_TRANSFER_AMOUNT)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "AllowanceExceeded");
+
+    let receiver_balance = deployer
+        .wallet
+        .provider()
+        .unwrap()
+        .get_asset_balance(receiver_wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+
+    assert_eq!(receiver_balance, DEFAULT_TRANSFER_AMOUNT);
+}
+
+#[tokio::test]
+async fn given_a_multisig_when_an_account_without_allowance_tries_to_spend_then_should_throw_allowance_exceeded(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            deployer.contract.contract_id(),
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Try to spend from an account without allowance
+    let caller = get_multisig_caller(&contract_id, wallets[2].clone());
+    let response = caller
+        .contract
+        .methods()
+        .spend_allowance(
+            base_asset_contract_id(),
+            Identity::Address(wallets[2].address().into()),
+            DEFAULT_TRANSFER_AMOUNT,
+        )
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "AllowanceExceeded");
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_a_periodic_allowance_when_the_period_has_passed_then_the_delegate_can_spend_it_again(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+    let provider = deployer.wallet.provider().unwrap().clone();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            deployer.contract.contract_id(),
+            DEFAULT_TRANSFER_AMOUNT * 2,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Grant an allowance that resets every period
+    let delegate = Identity::Address(wallets[2].address().into());
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            3600,
+            call_parameters_set_allowance(
+                delegate.clone(),
+                base_asset_contract_id(),
+                DEFAULT_TRANSFER_AMOUNT,
+                RESET_PERIOD,
+            ),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let _ = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // The delegate spends the whole allowance of the first period
+    let receiver_wallet = WalletUnlocked::new_random(None);
+    let receiver = Identity::Address(receiver_wallet.address().into());
+    let delegate_caller = get_multisig_caller(&contract_id, wallets[2].clone());
+    let _ = delegate_caller
+        .contract
+        .methods()
+        .spend_allowance(
+            base_asset_contract_id(),
+            receiver.clone(),
+            DEFAULT_TRANSFER_AMOUNT,
+        )
+        .append_variable_outputs(1)
+        .call()
+        .await
+        .unwrap();
+
+    let first_period = deployer
+        .contract
+        .methods()
+        .get_allowance(delegate.clone(), base_asset_contract_id())
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+
+    // The allowance can't be spent again in the same period
+    let response = delegate_caller
+        .contract
+        .methods()
+        .spend_allowance(
+            base_asset_contract_id(),
+            receiver.clone(),
+            DEFAULT_TRANSFER_AMOUNT,
+        )
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    validate_error(response, "AllowanceExceeded");
+
+    advance_block_time(&provider, RESET_PERIOD as i64).await;
+
+    // After the period has passed, the spent amount is reset
+    let _ = delegate_caller
+        .contract
+        .methods()
+        .spend_allowance(base_asset_contract_id(), receiver, DEFAULT_TRANSFER_AMOUNT)
+        .append_variable_outputs(1)
+        .call()
+        .await
+        .unwrap();
+
+    let second_period = deployer
+        .contract
+        .methods()
+        .get_allowance(delegate, base_asset_contract_id())
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+    let receiver_balance = provider
+        .get_asset_balance(receiver_wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+
+    assert_eq!(second_period.spent, DEFAULT_TRANSFER_AMOUNT);
+    assert!(second_period.last_reset >= first_period.last_reset + RESET_PERIOD);
+    assert_eq!(receiver_balance, DEFAULT_TRANSFER_AMOUNT * 2);
+}
@@ -0,0 +1,274 @@
+use fuels::prelude::*;
+use fuels::types::Identity;
+
+use crate::utils::abi::TransactionParameters;
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{
+    base_asset_contract_id, call_operation, deploy_counter, deploy_multisig, get_wallets,
+    transfer_operation, wallets_to_owners,
+};
+use crate::utils::validate_error;
+
+#[tokio::test]
+async fn given_a_multisig_with_a_proposed_batch_when_threshold_is_reached_and_the_transaction_executed_then_all_operations_are_performed(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the counter contract
+    let (counter_contract_id, counter_deployer) = deploy_counter(&wallets[0]).await.unwrap();
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            deployer.contract.contract_id(),
+            DEFAULT_TRANSFER_AMOUNT * 2,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Two receivers and a call to the counter
+    let first_receiver_wallet = WalletUnlocked::new_random(None);
+    let second_receiver_wallet = WalletUnlocked::new_random(None);
+    let operations = vec![
+        transfer_operation(
+            Identity::Address(first_receiver_wallet.address().into()),
+            DEFAULT_TRANSFER_AMOUNT,
+        ),
+        call_operation(&counter_contract_id),
+        transfer_operation(
+            Identity::Address(second_receiver_wallet.address().into()),
+            DEFAULT_TRANSFER_AMOUNT,
+        ),
+    ];
+
+    // Propose the batch tx
+    let response = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(deployer.contract.contract_id().into()),
+            3600,
+            TransactionParameters::Batch(operations.clone()),
+        )
+        .call()
+        .await
+        .unwrap();
+
+    let tx_id = response.value;
+
+    // Check that the batch is exposed by get_tx
+    let tx = deployer
+        .contract
+        .methods()
+        .get_tx(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+
+    assert_eq!(tx.tx_parameters, TransactionParameters::Batch(operations));
+
+    // Execute the batch tx because the threshold is 1
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(counter_contract_id)
+        .append_variable_outputs(2)
+        .call()
+        .await;
+
+    assert!(response.is_ok());
+
+    // Check the results of every operation
+    let counter_value = counter_deployer
+        .contract
+        .methods()
+        .get_counter()
+        .call()
+        .await
+        .unwrap()
+        .value;
+    let provider = deployer.wallet.provider().unwrap();
+    let contract_balance = provider
+        .get_contract_asset_balance(deployer.contract.contract_id(), base_asset_contract_id())
+        .await
+        .unwrap();
+    let first_receiver_balance = provider
+        .get_asset_balance(first_receiver_wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+    let second_receiver_balance = provider
+        .get_asset_balance(second_receiver_wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+
+    assert_eq!(counter_value, 5);
+    assert_eq!(contract_balance, 0);
+    assert_eq!(first_receiver_balance, DEFAULT_TRANSFER_AMOUNT);
+    assert_eq!(second_receiver_balance, DEFAULT_TRANSFER_AMOUNT);
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_a_proposed_batch_when_one_operation_reverts_then_no_operation_is_performed(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the counter contract
+    let (counter_contract_id, counter_deployer) = deploy_counter(&wallets[0]).await.unwrap();
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer funds for only one of the two transfers
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            deployer.contract.contract_id(),
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    let receiver_wallet = WalletUnlocked::new_random(None);
+    let receiver = Identity::Address(receiver_wallet.address().into());
+    let operations = vec![
+        transfer_operation(receiver.clone(), DEFAULT_TRANSFER_AMOUNT),
+        call_operation(&counter_contract_id),
+        transfer_operation(receiver, DEFAULT_TRANSFER_AMOUNT),
+    ];
+
+    // Propose the batch tx
+    let response = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(deployer.contract.contract_id().into()),
+            3600,
+            TransactionParameters::Batch(operations),
+        )
+        .call()
+        .await
+        .unwrap();
+
+    let tx_id = response.value;
+
+    // Try to execute the batch tx, the last transfer does not have enough funds
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(counter_contract_id)
+        .append_variable_outputs(2)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "InsufficientAssetAmount");
+
+    // Check that none of the operations were performed
+    let counter_value = counter_deployer
+        .contract
+        .methods()
+        .get_counter()
+        .call()
+        .await
+        .unwrap()
+        .value;
+    let provider = deployer.wallet.provider().unwrap();
+    let contract_balance = provider
+        .get_contract_asset_balance(deployer.contract.contract_id(), base_asset_contract_id())
+        .await
+        .unwrap();
+    let receiver_balance = provider
+        .get_asset_balance(receiver_wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+
+    assert_eq!(counter_value, 0);
+    assert_eq!(contract_balance, DEFAULT_TRANSFER_AMOUNT);
+    assert_eq!(receiver_balance, 0);
+
+    // Check that the batch is still pending
+    let tx = deployer
+        .contract
+        .methods()
+        .get_tx(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert!(tx.is_some());
+}
+
+#[tokio::test]
+async fn given_a_multisig_when_proposing_an_empty_batch_then_should_throw_empty_batch() {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Try to propose an empty batch
+    let response = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(deployer.contract.contract_id().into()),
+            3600,
+            TransactionParameters::Batch(vec![]),
+        )
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "EmptyBatch");
+}
@@ -0,0 +1,287 @@
+use fuels::prelude::*;
+use fuels::types::Identity;
+
+use crate::utils::abi::{CancellationReason, TransactionCancelled};
+use crate::utils::setup::{
+    call_parameters_remove_owner, deploy_multisig, get_multisig_caller, get_wallets,
+    transfer_parameters, wallets_to_owners,
+};
+use crate::utils::validate_error;
+
+#[tokio::test]
+async fn given_a_multisig_with_a_proposed_transaction_when_the_proposer_cancels_it_then_it_is_removed(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 2;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Cancel the transfer right away
+    let response = deployer
+        .contract
+        .methods()
+        .cancel_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Check the event
+    let events = response
+        .decode_logs_with_type::<TransactionCancelled>()
+        .unwrap();
+
+    assert_eq!(
+        events,
+        vec![TransactionCancelled {
+            tx_id,
+            reason: CancellationReason::CancelledByProposer,
+        }]
+    );
+
+    // Check that the transaction was removed
+    let tx = deployer
+        .contract
+        .methods()
+        .get_tx(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let active_tx_ids = deployer
+        .contract
+        .methods()
+        .get_active_tx_ids()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert!(tx.is_none());
+    assert!(active_tx_ids.is_empty());
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_a_proposed_transaction_when_another_owner_tries_to_cancel_it_then_should_throw_not_transaction_proposer(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 2;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Try to cancel the transfer from an owner that did not propose it
+    let caller = get_multisig_caller(&contract_id, wallets[1].clone());
+    let response = caller.contract.methods().cancel_tx(tx_id).call().await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "NotTransactionProposer");
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_a_proposed_transaction_when_enough_owners_reject_it_then_it_is_cancelled(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+    let threshold = 2;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // The first rejection still allows the threshold to be reached
+    let caller = get_multisig_caller(&contract_id, wallets[1].clone());
+    let _ = caller
+        .contract
+        .methods()
+        .reject_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    let tx = deployer
+        .contract
+        .methods()
+        .get_tx(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert!(tx.is_some());
+
+    // The second rejection makes the threshold unreachable
+    let caller = get_multisig_caller(&contract_id, wallets[2].clone());
+    let response = caller
+        .contract
+        .methods()
+        .reject_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Check the event
+    let events = response
+        .decode_logs_with_type::<TransactionCancelled>()
+        .unwrap();
+
+    assert_eq!(
+        events,
+        vec![TransactionCancelled {
+            tx_id,
+            reason: CancellationReason::RejectedByOwners,
+        }]
+    );
+
+    // Check that the transaction was removed
+    let tx = deployer
+        .contract
+        .methods()
+        .get_tx(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert!(tx.is_none());
+}
+
+#[tokio::test]
+async fn given_a_proposer_that_was_removed_as_owner_when_it_cancels_its_transaction_then_should_throw_not_proposer(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list)
+        .call()
+        .await
+        .unwrap();
+
+    // The second owner proposes a transaction
+    let second_owner = get_multisig_caller(&contract_id, wallets[1].clone());
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = second_owner
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // The first owner removes the second owner
+    let remove_tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            3600,
+            call_parameters_remove_owner(Identity::Address(wallets[1].address().into())),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+    let _ = deployer
+        .contract
+        .methods()
+        .execute_tx(remove_tx_id)
+        .append_contract(contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // The removed owner tries to cancel its transaction
+    let response = second_owner
+        .contract
+        .methods()
+        .cancel_tx(tx_id)
+        .call()
+        .await;
+
+    validate_error(response, "NotProposer");
+
+    // Check that the transaction is still pending
+    let tx = deployer
+        .contract
+        .methods()
+        .get_tx(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert!(tx.is_some());
+}
@@ -0,0 +1,165 @@
+use fuels::prelude::*;
+use fuels::types::Identity;
+
+use crate::utils::setup::{
+    call_parameters_update_config, deploy_multisig, get_multisig_caller, get_wallets,
+    wallets_to_owners,
+};
+use crate::utils::validate_error;
+
+#[tokio::test]
+async fn given_a_multisig_with_threshold_equal_to_the_owners_when_rotating_an_owner_and_lowering_the_threshold_at_once_then_the_config_is_updated(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let init_threshold = 2;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 2
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Replace the second owner by a new one and lower the threshold to 1 in the same proposal
+    let new_owners = wallets_to_owners(wallets[2..3].to_vec());
+    let removed_owner = owners_list[1].identity.clone();
+    let transaction_parameters =
+        call_parameters_update_config(new_owners.clone(), vec![removed_owner.clone()], Some(1));
+
+    // Propose the tx
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            3600,
+            transaction_parameters,
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Approve the tx from the second owner
+    let caller = get_multisig_caller(&contract_id, wallets[1].clone());
+    let _ = caller
+        .contract
+        .methods()
+        .approve_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Execute the tx
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(contract_id)
+        .call()
+        .await;
+
+    assert!(response.is_ok());
+
+    // Check the final owners and threshold
+    let owners = deployer
+        .contract
+        .methods()
+        .get_owners()
+        .call()
+        .await
+        .unwrap()
+        .value;
+    let threshold = deployer
+        .contract
+        .methods()
+        .get_threshold()
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(owners, vec![owners_list[0].clone(), new_owners[0].clone()]);
+    assert_eq!(threshold, 1);
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_threshold_equal_to_the_owners_when_removing_an_owner_without_lowering_the_threshold_then_should_throw_threshold_cannot_be_greater_than_owners(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let init_threshold = 2;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 2
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Remove the second owner keeping the threshold
+    let transaction_parameters =
+        call_parameters_update_config(vec![], vec![owners_list[1].identity.clone()], None);
+
+    // Propose the tx
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            3600,
+            transaction_parameters,
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Approve the tx from the second owner
+    let caller = get_multisig_caller(&contract_id, wallets[1].clone());
+    let _ = caller
+        .contract
+        .methods()
+        .approve_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Execute the tx
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(contract_id)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "ThresholdCannotBeGreaterThanOwners");
+
+    // Check that the owners did not change
+    let owners = deployer
+        .contract
+        .methods()
+        .get_owners()
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(owners, owners_list);
+}
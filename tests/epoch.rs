@@ -0,0 +1,174 @@
+use fuels::prelude::*;
+use fuels::types::Identity;
+
+use crate::utils::abi::{MultisigConfigurables, ThresholdChanged};
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{
+    call_parameters_add_owner, call_parameters_change_threshold, deploy_multisig,
+    deploy_multisig_with_configurables, get_wallets, transfer_parameters, wallets_to_owners,
+};
+use crate::utils::validate_error;
+
+#[tokio::test]
+async fn given_a_multisig_when_the_threshold_is_changed_then_the_config_epoch_is_incremented() {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let init_threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 1
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    let epoch_before = deployer
+        .contract
+        .methods()
+        .get_config_epoch()
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(epoch_before, 0);
+
+    // Propose and execute a threshold change
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            3600,
+            call_parameters_change_threshold(2),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(contract_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Check the event carries the new epoch
+    let logs = response
+        .decode_logs_with_type::<ThresholdChanged>()
+        .unwrap();
+
+    assert_eq!(
+        logs,
+        vec![ThresholdChanged {
+            new_threshold: 2,
+            config_epoch: 1,
+        }]
+    );
+
+    let epoch_after = deployer
+        .contract
+        .methods()
+        .get_config_epoch()
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(epoch_after, 1);
+}
+
+#[tokio::test]
+async fn given_a_multisig_invalidating_stale_proposals_when_the_owners_change_then_a_previous_proposal_can_not_be_executed(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let init_threshold = 1;
+
+    // Deploy the contract invalidating the proposals of previous configurations
+    let configurables = MultisigConfigurables::default()
+        .with_INVALIDATE_STALE_PROPOSALS(true)
+        .unwrap();
+    let (contract_id, deployer) = deploy_multisig_with_configurables(&wallets[0], configurables)
+        .await
+        .unwrap();
+
+    // Call the multisig constructor with threshold 1
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let stale_tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Propose and execute the addition of an owner
+    let new_owner = Identity::Address(Address::from(wallets[2].address()));
+    let add_owner_tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            3600,
+            call_parameters_add_owner(new_owner, 1),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let _ = deployer
+        .contract
+        .methods()
+        .execute_tx(add_owner_tx_id)
+        .append_contract(contract_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Try to execute the transfer proposed under the previous owners
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(stale_tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "ConfigEpochChanged");
+}
@@ -0,0 +1,234 @@
+use fuels::prelude::*;
+
+use crate::utils::abi::{
+    TransactionApproved, TransactionExecuted, TransactionProposed, TransactionQueued,
+    TransactionRejected, TransactionRemoved, TransactionStatus, VoteRevoked,
+};
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{
+    deploy_multisig, get_multisig_caller, get_wallets, transfer_parameters, wallets_to_owners,
+};
+
+#[tokio::test]
+async fn given_a_transaction_when_it_is_proposed_voted_and_executed_then_the_events_carry_the_full_state(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+    let threshold = 2;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let response = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver.clone(), 3600, transaction_parameters.clone())
+        .call()
+        .await
+        .unwrap();
+    let tx_id = response.value;
+
+    let tx = deployer
+        .contract
+        .methods()
+        .get_tx(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+
+    // Check the proposal event
+    let events = response
+        .decode_logs_with_type::<TransactionProposed>()
+        .unwrap();
+
+    assert_eq!(
+        events,
+        vec![TransactionProposed {
+            tx_id,
+            proposer: owners_list[0].identity.clone(),
+            to: receiver,
+            valid_until: tx.valid_until,
+            transaction_parameters,
+            config_epoch: 0,
+            gas_refund: None,
+            approvals_count: 1,
+        }]
+    );
+
+    // Reject the transaction from the third owner
+    let response = get_multisig_caller(&contract_id, wallets[2].clone())
+        .contract
+        .methods()
+        .reject_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    let events = response
+        .decode_logs_with_type::<TransactionRejected>()
+        .unwrap();
+
+    assert_eq!(
+        events,
+        vec![TransactionRejected {
+            tx_id,
+            owner: owners_list[2].identity.clone(),
+            weight: 1,
+            approvals_count: 1,
+            rejections_count: 1,
+        }]
+    );
+
+    // Approve the transaction from the second owner, reaching the threshold
+    let response = get_multisig_caller(&contract_id, wallets[1].clone())
+        .contract
+        .methods()
+        .approve_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    let approved_events = response
+        .decode_logs_with_type::<TransactionApproved>()
+        .unwrap();
+    let queued_events = response
+        .decode_logs_with_type::<TransactionQueued>()
+        .unwrap();
+
+    assert_eq!(
+        approved_events,
+        vec![TransactionApproved {
+            tx_id,
+            owner: owners_list[1].identity.clone(),
+            weight: 1,
+            approvals_count: 2,
+            rejections_count: 1,
+        }]
+    );
+    assert_eq!(queued_events.len(), 1);
+    assert_eq!(queued_events[0].tx_id, tx_id);
+
+    // Without an execution delay the transaction can be executed as soon as it is queued
+    assert_eq!(queued_events[0].executable_at, queued_events[0].queued_at);
+
+    // Execute the transaction
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await
+        .unwrap();
+
+    let executed_events = response
+        .decode_logs_with_type::<TransactionExecuted>()
+        .unwrap();
+    let removed_events = response
+        .decode_logs_with_type::<TransactionRemoved>()
+        .unwrap();
+
+    assert_eq!(
+        executed_events,
+        vec![TransactionExecuted {
+            tx_id,
+            executor: owners_list[0].identity.clone(),
+        }]
+    );
+    assert_eq!(
+        removed_events,
+        vec![TransactionRemoved {
+            tx_id,
+            status: TransactionStatus::Executed,
+            finalized_by: owners_list[0].identity.clone(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn given_an_approved_transaction_when_the_approval_is_revoked_then_the_event_carries_the_updated_tallies(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+    let threshold = 3;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer and approve it from the second owner
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let caller = get_multisig_caller(&contract_id, wallets[1].clone());
+    let _ = caller
+        .contract
+        .methods()
+        .approve_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Revoke the approval
+    let response = caller
+        .contract
+        .methods()
+        .revoke_vote(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    let events = response.decode_logs_with_type::<VoteRevoked>().unwrap();
+
+    assert_eq!(
+        events,
+        vec![VoteRevoked {
+            tx_id,
+            owner: owners_list[1].identity.clone(),
+            was_approval: true,
+            weight: 1,
+            approvals_count: 1,
+            rejections_count: 0,
+        }]
+    );
+}
@@ -0,0 +1,247 @@
+use fuels::prelude::*;
+use fuels::types::Identity;
+
+use crate::utils::abi::{ExecutorPolicy, Roles};
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{
+    call_parameters_set_executor_policy, call_parameters_set_roles, deploy_multisig,
+    execute_self_call, get_multisig_caller, get_wallets, transfer_parameters, wallets_to_owners,
+};
+use crate::utils::validate_error;
+
+#[tokio::test]
+async fn given_a_multisig_when_it_is_initialized_then_the_executor_policy_is_the_allowlist() {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    let policy = deployer
+        .contract
+        .methods()
+        .get_executor_policy()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(policy, ExecutorPolicy::Allowlist);
+}
+
+#[tokio::test]
+async fn given_an_owners_only_executor_policy_when_a_non_owner_executor_executes_then_should_throw_not_executor(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Grant the executor role to a non-owner and restrict the execution to the owners
+    let executor = Identity::Address(Address::from(wallets[2].address()));
+    let executor_roles = Roles {
+        proposer: false,
+        approver: false,
+        rejecter: false,
+        executor: true,
+    };
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_set_roles(executor, executor_roles),
+    )
+    .await;
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_set_executor_policy(ExecutorPolicy::OwnersOnly),
+    )
+    .await;
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Try to execute the transfer from the non-owner executor
+    let caller = get_multisig_caller(&contract_id, wallets[2].clone());
+    let response = caller
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "NotExecutor");
+
+    // An owner can still execute it
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn given_an_anyone_executor_policy_when_an_account_without_roles_executes_then_the_transfer_is_performed(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Allow anyone to execute
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_set_executor_policy(ExecutorPolicy::Anyone),
+    )
+    .await;
+
+    // Propose a transfer
+    let (receiver_wallet, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Execute the transfer from an account without roles
+    let caller = get_multisig_caller(&contract_id, wallets[2].clone());
+    let _ = caller
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await
+        .unwrap();
+
+    // Check the receiver balance
+    let receiver_balance = deployer
+        .wallet
+        .provider()
+        .unwrap()
+        .get_asset_balance(receiver_wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+
+    assert_eq!(receiver_balance, DEFAULT_TRANSFER_AMOUNT);
+}
+
+#[tokio::test]
+async fn given_an_allowlist_executor_policy_when_an_account_without_roles_executes_then_should_throw_not_executor(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor, which sets the allowlist policy
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Try to execute the transfer from an account without roles
+    let caller = get_multisig_caller(&contract_id, wallets[2].clone());
+    let response = caller
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    validate_error(response, "NotExecutor");
+}
@@ -0,0 +1,175 @@
+use fuels::prelude::*;
+
+use crate::utils::abi::{TransactionParameters, TransactionStatus, TransferParams};
+use crate::utils::setup::{
+    base_asset_contract_id, call_parameters_always_revert, deploy_multisig, deploy_reverter,
+    get_wallets, transfer_parameters, wallets_to_owners,
+};
+use crate::utils::validate_error;
+
+#[tokio::test]
+async fn given_a_multisig_when_proposing_a_call_to_an_address_then_should_throw_can_only_call_contracts(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a contract call to an address, which can never be executed
+    let (_, receiver, _) = transfer_parameters();
+    let response = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, call_parameters_always_revert(42))
+        .call()
+        .await;
+
+    validate_error(response, "CanOnlyCallContracts");
+
+    // Check that no slot was taken
+    let active_tx_ids = deployer
+        .contract
+        .methods()
+        .get_active_tx_ids()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert!(active_tx_ids.is_empty());
+}
+
+#[tokio::test]
+async fn given_a_multisig_when_proposing_a_transfer_without_a_value_then_should_throw_transfer_requires_a_value(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer without a value, which can never be executed
+    let (_, receiver, _) = transfer_parameters();
+    let transaction_parameters = TransactionParameters::Transfer(TransferParams {
+        asset_id: base_asset_contract_id(),
+        value: None,
+    });
+    let response = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await;
+
+    validate_error(response, "TransferRequiresAValue");
+}
+
+#[tokio::test]
+async fn given_a_transaction_that_calls_a_reverting_contract_when_executed_then_it_reverts_and_the_proposer_can_cancel_it(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Deploy the reverter contract
+    let (reverter_contract_id, _) = deploy_reverter(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a call to the reverter contract
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(reverter_contract_id.clone().into()),
+            3600,
+            call_parameters_always_revert(42),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Execute the transaction. A revert inside the called contract can not be caught by the FuelVM.
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(reverter_contract_id)
+        .call()
+        .await;
+
+    assert!(response.is_err());
+
+    // Check the transaction is still pending
+    let status = deployer
+        .contract
+        .methods()
+        .get_tx_status(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(status, Some(TransactionStatus::Pending));
+
+    // The proposer cancels the transaction to free its slot
+    deployer
+        .contract
+        .methods()
+        .cancel_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    let status = deployer
+        .contract
+        .methods()
+        .get_tx_status(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let active_tx_ids = deployer
+        .contract
+        .methods()
+        .get_active_tx_ids()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(status, Some(TransactionStatus::Cancelled));
+    assert!(active_tx_ids.is_empty());
+}
@@ -0,0 +1,343 @@
+use fuels::prelude::*;
+use fuels::types::{ContractId, Identity};
+
+use crate::utils::abi::{GuardConfigurables, TransactionStatus};
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{
+    call_parameters, call_parameters_set_guard, deploy_counter, deploy_guard, deploy_multisig,
+    execute_self_call, get_wallets, transfer_parameters, wallets_to_owners,
+};
+use crate::utils::validate_error;
+
+const MIN_BALANCE: u64 = 100;
+
+#[tokio::test]
+async fn given_a_multisig_with_a_guard_when_executing_an_allowed_transfer_then_it_is_executed() {
+    let wallets = get_wallets(2).await;
+
+    // Keep a minimum balance that the transfer respects
+    let configurables = GuardConfigurables::default()
+        .with_MIN_BALANCE_ASSET(AssetId::BASE)
+        .unwrap()
+        .with_MIN_BALANCE(MIN_BALANCE)
+        .unwrap();
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig and the guard contracts
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+    let guard_contract_id = deploy_guard(&wallets[0], configurables).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list)
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT + MIN_BALANCE,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Set the guard
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_set_guard(Some(ContractId::from(&guard_contract_id))),
+    )
+    .await;
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Execute the transfer
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(guard_contract_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn given_a_guard_blocking_a_contract_when_executing_a_call_to_it_then_should_throw_transaction_blocked_by_guard(
+) {
+    let wallets = get_wallets(2).await;
+
+    // Deploy the contract that is going to be blocked
+    let (counter_contract_id, _) = deploy_counter(&wallets[0]).await.unwrap();
+
+    // Block any call to the counter
+    let configurables = GuardConfigurables::default()
+        .with_BLOCKED_CONTRACT(ContractId::from(&counter_contract_id))
+        .unwrap();
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig and the guard contracts
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+    let guard_contract_id = deploy_guard(&wallets[0], configurables).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list)
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Set the guard
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_set_guard(Some(ContractId::from(&guard_contract_id))),
+    )
+    .await;
+
+    // Propose a call to the counter
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(counter_contract_id.clone().into()),
+            3600,
+            call_parameters(),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Try to execute the call
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(guard_contract_id)
+        .append_contract(counter_contract_id)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "TransactionBlockedByGuard");
+
+    // Check that the transaction is still pending
+    let status = deployer
+        .contract
+        .methods()
+        .get_tx_status(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(status, Some(TransactionStatus::Pending));
+}
+
+#[tokio::test]
+async fn given_a_guard_with_a_minimum_balance_when_a_transfer_drops_below_it_then_should_throw_transaction_blocked_by_guard(
+) {
+    let wallets = get_wallets(2).await;
+
+    // Keep a minimum balance that the transfer does not respect
+    let configurables = GuardConfigurables::default()
+        .with_MIN_BALANCE_ASSET(AssetId::BASE)
+        .unwrap()
+        .with_MIN_BALANCE(MIN_BALANCE)
+        .unwrap();
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig and the guard contracts
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+    let guard_contract_id = deploy_guard(&wallets[0], configurables).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list)
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT + MIN_BALANCE / 2,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Set the guard
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_set_guard(Some(ContractId::from(&guard_contract_id))),
+    )
+    .await;
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Try to execute the transfer
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(guard_contract_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "TransactionBlockedByGuard");
+}
+
+#[tokio::test]
+async fn given_a_guard_that_blocks_every_transaction_when_the_owners_remove_it_then_the_transactions_can_be_executed_again(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Keep a minimum balance that no execution can respect
+    let configurables = GuardConfigurables::default()
+        .with_MIN_BALANCE_ASSET(AssetId::BASE)
+        .unwrap()
+        .with_MIN_BALANCE(u64::MAX)
+        .unwrap();
+
+    // Deploy the multisig and the guard contracts
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+    let guard_contract_id = deploy_guard(&wallets[0], configurables).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list)
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Set the guard
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_set_guard(Some(ContractId::from(&guard_contract_id))),
+    )
+    .await;
+
+    // Propose a transfer, which the guard blocks
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(guard_contract_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    validate_error(response, "TransactionBlockedByGuard");
+
+    // Remove the guard, which is not checked by it
+    execute_self_call(&deployer, &contract_id, call_parameters_set_guard(None)).await;
+
+    let guard = deployer
+        .contract
+        .methods()
+        .get_guard()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(guard, None);
+
+    // The transfer can be executed without the guard
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    assert!(response.is_ok());
+}
@@ -3,4 +3,26 @@ mod threshold;
 mod transactions;
 mod initialization;
 mod self_call;
+mod batch;
+mod signatures;
+mod predicate;
+mod weights;
+mod roles;
+mod allowances;
+mod timelock;
+mod votes;
+mod cancel;
+mod history;
+mod failures;
+mod config;
+mod epoch;
+mod removal;
+mod executor;
+mod refund;
+mod events;
+mod modules;
+mod guard;
+mod recovery;
+mod inactivity;
+mod proxy;
 mod utils;
@@ -0,0 +1,163 @@
+use fuels::prelude::*;
+use fuels::types::U256;
+
+use crate::utils::abi::TransactionStatus;
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{deploy_multisig, get_wallets, transfer_parameters, wallets_to_owners};
+
+#[tokio::test]
+async fn given_a_multisig_when_transactions_are_executed_and_cancelled_then_their_final_status_is_kept_in_the_history(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            deployer.contract.contract_id(),
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Propose two transfers
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let executed_tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver.clone(), 3600, transaction_parameters.clone())
+        .call()
+        .await
+        .unwrap()
+        .value;
+    let cancelled_tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Check the status of a pending tx
+    let status = deployer
+        .contract
+        .methods()
+        .get_tx_status(executed_tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(status, Some(TransactionStatus::Pending));
+
+    // Execute the first transfer and cancel the second one
+    let _ = deployer
+        .contract
+        .methods()
+        .execute_tx(executed_tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await
+        .unwrap();
+    let _ = deployer
+        .contract
+        .methods()
+        .cancel_tx(cancelled_tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Check the final status of both txs
+    let executed_status = deployer
+        .contract
+        .methods()
+        .get_tx_status(executed_tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let cancelled_status = deployer
+        .contract
+        .methods()
+        .get_tx_status(cancelled_tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let unknown_status = deployer
+        .contract
+        .methods()
+        .get_tx_status(U256::from(100))
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(executed_status, Some(TransactionStatus::Executed));
+    assert_eq!(cancelled_status, Some(TransactionStatus::Cancelled));
+    assert_eq!(unknown_status, None);
+
+    // Check the archived record of the executed tx
+    let record = deployer
+        .contract
+        .methods()
+        .get_tx_record(executed_tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+
+    assert_eq!(record.tx_id, executed_tx_id);
+    assert_eq!(record.finalized_by, owners_list[0].identity);
+    assert_eq!(record.approvals_count, 1);
+    assert_eq!(record.rejections_count, 0);
+    assert!(record.finalized_at > 0);
+
+    // Check the history
+    let history_len = deployer
+        .contract
+        .methods()
+        .get_history_len()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let history = deployer
+        .contract
+        .methods()
+        .get_history_page(0, 10)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(history_len, 2);
+    assert_eq!(
+        history
+            .iter()
+            .map(|record| (record.tx_id, record.status.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (executed_tx_id, TransactionStatus::Executed),
+            (cancelled_tx_id, TransactionStatus::Cancelled),
+        ]
+    );
+}
@@ -0,0 +1,241 @@
+use fuels::prelude::*;
+use fuels::types::{bech32::Bech32ContractId, Identity};
+
+use crate::utils::abi::InactivityClaimed;
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{
+    advance_block_time, base_asset_contract_id, call_parameters_set_allowance,
+    call_parameters_set_inactivity_switch, deploy_multisig, execute_self_call, get_multisig_caller,
+    get_wallets, transfer_parameters, wallets_to_owners, MultisigCaller,
+};
+use crate::utils::validate_error;
+
+const INACTIVITY_PERIOD: u64 = 30 * 24 * 3600;
+
+async fn setup_multisig_with_inactivity_switch(
+    wallets: &[WalletUnlocked],
+    beneficiary: Identity,
+) -> (Bech32ContractId, MultisigCaller) {
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list)
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Set the beneficiary, the inactivity period and the assets to transfer
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_set_inactivity_switch(
+            Some(beneficiary),
+            INACTIVITY_PERIOD,
+            vec![base_asset_contract_id()],
+        ),
+    )
+    .await;
+
+    (contract_id, deployer)
+}
+
+#[tokio::test]
+async fn given_inactive_owners_when_the_inactivity_period_has_passed_then_the_assets_are_transferred_to_the_beneficiary(
+) {
+    let wallets = get_wallets(3).await;
+    let beneficiary_wallet = WalletUnlocked::new_random(None);
+    let beneficiary = Identity::Address(beneficiary_wallet.address().into());
+    let (contract_id, deployer) =
+        setup_multisig_with_inactivity_switch(&wallets, beneficiary.clone()).await;
+    let provider = deployer.wallet.provider().unwrap().clone();
+
+    let last_activity = deployer
+        .contract
+        .methods()
+        .get_last_activity()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    advance_block_time(&provider, INACTIVITY_PERIOD as i64).await;
+
+    // Anyone can claim the assets for the beneficiary
+    let claimer = get_multisig_caller(&contract_id, wallets[2].clone());
+    let response = claimer
+        .contract
+        .methods()
+        .claim_inactive()
+        .append_variable_outputs(1)
+        .call()
+        .await
+        .unwrap();
+
+    // Check the event
+    let events = response
+        .decode_logs_with_type::<InactivityClaimed>()
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![InactivityClaimed {
+            beneficiary,
+            asset_id: base_asset_contract_id(),
+            amount: DEFAULT_TRANSFER_AMOUNT,
+            last_activity,
+        }]
+    );
+
+    // Check the balances
+    let contract_balance = provider
+        .get_contract_asset_balance(&contract_id, base_asset_contract_id())
+        .await
+        .unwrap();
+    let beneficiary_balance = provider
+        .get_asset_balance(beneficiary_wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+
+    assert_eq!(contract_balance, 0);
+    assert_eq!(beneficiary_balance, DEFAULT_TRANSFER_AMOUNT);
+}
+
+#[tokio::test]
+async fn given_inactive_owners_when_the_inactivity_period_has_not_passed_then_the_assets_can_not_be_claimed(
+) {
+    let wallets = get_wallets(3).await;
+    let beneficiary_wallet = WalletUnlocked::new_random(None);
+    let beneficiary = Identity::Address(beneficiary_wallet.address().into());
+    let (contract_id, deployer) =
+        setup_multisig_with_inactivity_switch(&wallets, beneficiary).await;
+    let provider = deployer.wallet.provider().unwrap().clone();
+
+    advance_block_time(&provider, INACTIVITY_PERIOD as i64 / 2).await;
+
+    let claimer = get_multisig_caller(&contract_id, wallets[2].clone());
+    let response = claimer
+        .contract
+        .methods()
+        .claim_inactive()
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    validate_error(response, "OwnersStillActive");
+
+    // Check that the funds are still in the contract
+    let contract_balance = provider
+        .get_contract_asset_balance(&contract_id, base_asset_contract_id())
+        .await
+        .unwrap();
+    assert_eq!(contract_balance, DEFAULT_TRANSFER_AMOUNT);
+}
+
+#[tokio::test]
+async fn given_an_owner_action_during_the_inactivity_period_when_claiming_after_the_original_period_then_it_reverts(
+) {
+    let wallets = get_wallets(3).await;
+    let beneficiary_wallet = WalletUnlocked::new_random(None);
+    let beneficiary = Identity::Address(beneficiary_wallet.address().into());
+    let (contract_id, deployer) =
+        setup_multisig_with_inactivity_switch(&wallets, beneficiary).await;
+    let provider = deployer.wallet.provider().unwrap().clone();
+
+    advance_block_time(&provider, INACTIVITY_PERIOD as i64 / 2).await;
+
+    // An owner proposes a transaction, which restarts the inactivity period
+    let second_owner = get_multisig_caller(&contract_id, wallets[1].clone());
+    let (_, receiver, tx_parameters) = transfer_parameters();
+    second_owner
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, tx_parameters)
+        .call()
+        .await
+        .unwrap();
+
+    advance_block_time(&provider, INACTIVITY_PERIOD as i64 / 2).await;
+
+    // The original period has passed, but not since the last activity
+    let claimer = get_multisig_caller(&contract_id, wallets[2].clone());
+    let response = claimer
+        .contract
+        .methods()
+        .claim_inactive()
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    validate_error(response, "OwnersStillActive");
+}
+
+#[tokio::test]
+async fn given_an_owner_spending_its_allowance_during_the_inactivity_period_when_claiming_after_the_original_period_then_it_reverts(
+) {
+    let wallets = get_wallets(3).await;
+    let beneficiary_wallet = WalletUnlocked::new_random(None);
+    let beneficiary = Identity::Address(beneficiary_wallet.address().into());
+    let (contract_id, deployer) =
+        setup_multisig_with_inactivity_switch(&wallets, beneficiary).await;
+    let provider = deployer.wallet.provider().unwrap().clone();
+
+    // Grant an allowance to the second owner
+    let second_owner = get_multisig_caller(&contract_id, wallets[1].clone());
+    let spent_amount = DEFAULT_TRANSFER_AMOUNT / 2;
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_set_allowance(
+            Identity::Address(wallets[1].address().into()),
+            base_asset_contract_id(),
+            spent_amount,
+            0,
+        ),
+    )
+    .await;
+
+    advance_block_time(&provider, INACTIVITY_PERIOD as i64 / 2).await;
+
+    // The owner spends its allowance, which restarts the inactivity period
+    let (_, receiver, _) = transfer_parameters();
+    second_owner
+        .contract
+        .methods()
+        .spend_allowance(base_asset_contract_id(), receiver, spent_amount)
+        .append_variable_outputs(1)
+        .call()
+        .await
+        .unwrap();
+
+    advance_block_time(&provider, INACTIVITY_PERIOD as i64 / 2).await;
+
+    // The original period has passed, but not since the last activity
+    let claimer = get_multisig_caller(&contract_id, wallets[2].clone());
+    let response = claimer
+        .contract
+        .methods()
+        .claim_inactive()
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    validate_error(response, "OwnersStillActive");
+}
@@ -1,7 +1,7 @@
 use fuels::{prelude::*, types::U256};
 
 use crate::utils::setup::{
-    deploy_multisig, get_wallets, transfer_parameters, wallets_to_identities,
+    deploy_multisig, get_wallets, transfer_parameters, wallets_to_owners,
 };
 use crate::utils::validate_error;
 
@@ -27,7 +27,7 @@ async fn given_a_multisig_not_initialized_when_try_to_change_threshold_then_it_w
 async fn given_a_multisig_not_initialized_when_try_to_add_owner_call_then_it_will_throw_not_initialized(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
 
     // Deploy the multisig contract
     let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
@@ -36,7 +36,7 @@ async fn given_a_multisig_not_initialized_when_try_to_add_owner_call_then_it_wil
     let response = deployer
         .contract
         .methods()
-        .add_owner(owners_list[0].clone())
+        .add_owner(owners_list[0].identity.clone(), 1)
         .call()
         .await;
 
@@ -51,7 +51,7 @@ async fn given_a_multisig_not_initialized_when_try_to_add_owner_call_then_it_wil
 async fn given_a_multisig_not_initialized_when_try_to_remove_owner_call_then_it_will_throw_not_initialized(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
 
     // Deploy the multisig contract
     let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
@@ -60,7 +60,7 @@ async fn given_a_multisig_not_initialized_when_try_to_remove_owner_call_then_it_
     let response = deployer
         .contract
         .methods()
-        .remove_owner(owners_list[0].clone())
+        .remove_owner(owners_list[0].identity.clone())
         .call()
         .await;
 
@@ -0,0 +1,285 @@
+use fuels::prelude::*;
+use fuels::types::{ContractId, Identity};
+
+use crate::utils::abi::{GuardConfigurables, ModuleEnabled, TransferParams};
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{
+    call_parameters_change_threshold, call_parameters_disable_module,
+    call_parameters_enable_module, call_parameters_set_guard, deploy_guard, deploy_module,
+    deploy_multisig, execute_self_call, get_wallets, wallets_to_owners,
+};
+use crate::utils::validate_error;
+
+#[tokio::test]
+async fn given_an_enabled_module_when_it_executes_a_transfer_then_the_transfer_is_performed_without_approvals(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 2;
+
+    // Deploy the multisig and the module contracts
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+    let (module_contract_id, module) = deploy_module(&wallets[0]).await.unwrap();
+    let module_id = ContractId::from(&module_contract_id);
+
+    // Call the multisig constructor with threshold 1 to enable the module
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(1, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Enable the module
+    let response = execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_enable_module(module_id),
+    )
+    .await;
+
+    let events = response.decode_logs_with_type::<ModuleEnabled>().unwrap();
+
+    assert_eq!(events, vec![ModuleEnabled { module: module_id }]);
+
+    let modules = deployer
+        .contract
+        .methods()
+        .get_modules()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(modules, vec![module_id]);
+
+    // Raise the threshold, the module does not need any approval anyway
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_change_threshold(threshold),
+    )
+    .await;
+
+    // Transfer from the multisig through the module
+    let receiver_wallet = WalletUnlocked::new_random(None);
+    let receiver = Identity::Address(receiver_wallet.address().into());
+    let _ = module
+        .contract
+        .methods()
+        .transfer_from_multisig(
+            ContractId::from(&contract_id),
+            receiver,
+            AssetId::BASE,
+            DEFAULT_TRANSFER_AMOUNT,
+        )
+        .with_contracts(&[&deployer.contract])
+        .append_variable_outputs(1)
+        .call()
+        .await
+        .unwrap();
+
+    // Check the receiver balance
+    let receiver_balance = deployer
+        .wallet
+        .provider()
+        .unwrap()
+        .get_asset_balance(receiver_wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+
+    assert_eq!(receiver_balance, DEFAULT_TRANSFER_AMOUNT);
+}
+
+#[tokio::test]
+async fn given_a_contract_that_is_not_a_module_when_it_executes_a_transfer_then_should_throw_unauthorized(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig and the module contracts
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+    let (module_contract_id, module) = deploy_module(&wallets[0]).await.unwrap();
+    let module_id = ContractId::from(&module_contract_id);
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Enable and disable the module
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_enable_module(module_id),
+    )
+    .await;
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_disable_module(module_id),
+    )
+    .await;
+
+    let is_module = deployer
+        .contract
+        .methods()
+        .is_module(module_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert!(!is_module);
+
+    // Try to transfer from the multisig through the disabled module
+    let receiver = Identity::Address(WalletUnlocked::new_random(None).address().into());
+    let response = module
+        .contract
+        .methods()
+        .transfer_from_multisig(
+            ContractId::from(&contract_id),
+            receiver.clone(),
+            AssetId::BASE,
+            DEFAULT_TRANSFER_AMOUNT,
+        )
+        .with_contracts(&[&deployer.contract])
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "Unauthorized");
+
+    // An account can not call it either
+    let response = deployer
+        .contract
+        .methods()
+        .exec_from_module(
+            receiver,
+            TransactionParameters::Transfer(TransferParams {
+                asset_id: AssetId::BASE,
+                value: Some(DEFAULT_TRANSFER_AMOUNT),
+            }),
+        )
+        .call()
+        .await;
+
+    validate_error(response, "Unauthorized");
+}
+
+#[tokio::test]
+async fn given_a_guard_keeping_a_minimum_balance_when_a_module_transfer_drops_below_it_then_should_throw_transaction_blocked_by_guard(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let min_balance = 100;
+
+    // Deploy the multisig, the module and the guard contracts
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+    let (module_contract_id, module) = deploy_module(&wallets[0]).await.unwrap();
+    let configurables = GuardConfigurables::default()
+        .with_MIN_BALANCE_ASSET(AssetId::BASE)
+        .unwrap()
+        .with_MIN_BALANCE(min_balance)
+        .unwrap();
+    let guard_contract_id = deploy_guard(&wallets[0], configurables).await.unwrap();
+
+    // Call the multisig constructor with threshold 1
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(1, owners_list)
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Enable the module and set the guard
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_enable_module(ContractId::from(&module_contract_id)),
+    )
+    .await;
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_set_guard(Some(ContractId::from(&guard_contract_id))),
+    )
+    .await;
+
+    // Try to transfer the whole balance through the module
+    let receiver_wallet = WalletUnlocked::new_random(None);
+    let receiver = Identity::Address(receiver_wallet.address().into());
+    let response = module
+        .contract
+        .methods()
+        .transfer_from_multisig(
+            ContractId::from(&contract_id),
+            receiver,
+            AssetId::BASE,
+            DEFAULT_TRANSFER_AMOUNT,
+        )
+        .with_contract_ids(&[contract_id.clone(), guard_contract_id])
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    validate_error(response, "TransactionBlockedByGuard");
+
+    // Check that the funds are still in the contract
+    let contract_balance = deployer
+        .wallet
+        .provider()
+        .unwrap()
+        .get_contract_asset_balance(&contract_id, AssetId::BASE)
+        .await
+        .unwrap();
+
+    assert_eq!(contract_balance, DEFAULT_TRANSFER_AMOUNT);
+}
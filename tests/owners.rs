@@ -1,9 +1,10 @@
 use fuels::prelude::*;
 use fuels::types::Identity;
 
+use crate::utils::abi::{Owner, OwnerSwapped};
 use crate::utils::setup::{
-    call_parameters_add_owner, call_parameters_remove_owner, deploy_multisig, get_multisig_caller,
-    get_wallets, wallets_to_identities,
+    call_parameters_add_owner, call_parameters_remove_owner, call_parameters_swap_owner,
+    deploy_multisig, get_multisig_caller, get_wallets, transfer_parameters, wallets_to_owners,
 };
 use crate::utils::validate_error;
 
@@ -11,7 +12,7 @@ use crate::utils::validate_error;
 async fn given_a_multisig_with_one_owner_and_threshold_one_when_proposing_a_tx_it_should_be_executed_with_no_errors(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let init_threshold = 1;
 
     // Deploy the multisig contract
@@ -41,7 +42,7 @@ async fn given_a_multisig_with_one_owner_and_threshold_one_when_proposing_a_tx_i
     let new_owner = Identity::Address(Address::from(wallets[2].address()));
 
     // Get call parameters
-    let transaction_parameters = call_parameters_add_owner(new_owner.clone());
+    let transaction_parameters = call_parameters_add_owner(new_owner.clone(), 1);
 
     // Propose the tx
     let response = deployer
@@ -84,7 +85,7 @@ async fn given_a_multisig_with_one_owner_and_threshold_one_when_proposing_a_tx_i
 async fn given_a_multisig_with_threshold_three_when_proposing_a_add_owner_and_is_approved_by_two_it_should_be_executed_with_no_errors(
 ) {
     let wallets = get_wallets(4).await;
-    let owners_list = wallets_to_identities(wallets[0..3].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
     let init_threshold = 3;
 
     // Deploy the multisig contract
@@ -114,7 +115,7 @@ async fn given_a_multisig_with_threshold_three_when_proposing_a_add_owner_and_is
     let new_owner = Identity::Address(Address::from(wallets[3].address()));
 
     // Get call parameters
-    let transaction_parameters = call_parameters_add_owner(new_owner.clone());
+    let transaction_parameters = call_parameters_add_owner(new_owner.clone(), 1);
 
     // Propose the tx
     let response = deployer
@@ -173,7 +174,7 @@ async fn given_a_multisig_with_threshold_three_when_proposing_a_add_owner_and_is
 async fn given_a_multisig_with_some_owners_when_trying_to_add_an_exisiting_owner_it_should_fail_throwing_already_owner_error(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let init_threshold = 1;
 
     // Deploy the multisig contract
@@ -203,7 +204,7 @@ async fn given_a_multisig_with_some_owners_when_trying_to_add_an_exisiting_owner
     let new_owner = Identity::Address(Address::from(wallets[1].address()));
 
     // Get call parameters
-    let transaction_parameters = call_parameters_add_owner(new_owner.clone());
+    let transaction_parameters = call_parameters_add_owner(new_owner.clone(), 1);
 
     // Propose the tx
     let response = deployer
@@ -250,7 +251,7 @@ async fn given_a_multisig_with_some_owners_when_trying_to_add_an_exisiting_owner
 async fn given_a_multisig_with_max_owners_reached_when_trying_to_add_an_owner_it_should_throw_max_owners_error(
 ) {
     let wallets = get_wallets(11).await;
-    let owners_list = wallets_to_identities(wallets[0..10].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..10].to_vec());
     let init_threshold = 1;
 
     // Deploy the multisig contract
@@ -280,7 +281,7 @@ async fn given_a_multisig_with_max_owners_reached_when_trying_to_add_an_owner_it
     let new_owner = Identity::Address(Address::from(wallets[10].address()));
 
     // Get call parameters
-    let transaction_parameters = call_parameters_add_owner(new_owner.clone());
+    let transaction_parameters = call_parameters_add_owner(new_owner.clone(), 1);
 
     // Propose the tx
     let response = deployer
@@ -327,7 +328,7 @@ async fn given_a_multisig_with_max_owners_reached_when_trying_to_add_an_owner_it
 async fn given_a_multisig_with_threshold_1_and_3_owners_when_trying_to_remove_one_of_them_it_should_be_removed_successfully(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let init_threshold = 1;
 
     // Deploy the multisig contract
@@ -400,7 +401,7 @@ async fn given_a_multisig_with_threshold_1_and_3_owners_when_trying_to_remove_on
 async fn given_a_multisig_with_threshold_3_and_4_owners_when_trying_to_remove_one_of_them_it_should_be_removed_successfully(
 ) {
     let wallets = get_wallets(4).await;
-    let owners_list = wallets_to_identities(wallets[0..4].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..4].to_vec());
     let init_threshold = 3;
 
     // Deploy the multisig contract
@@ -489,7 +490,7 @@ async fn given_a_multisig_with_threshold_3_and_4_owners_when_trying_to_remove_on
 async fn given_a_multisig_with_threshold_one_and_3_owners_when_trying_to_remove_one_that_is_not_owner_it_should_throw_not_owner_error_and_revert(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let init_threshold = 1;
 
     // Deploy the multisig contract
@@ -566,7 +567,7 @@ async fn given_a_multisig_with_threshold_one_and_3_owners_when_trying_to_remove_
 async fn given_a_multisig_with_a_single_owner_when_trying_to_remove_that_owner_it_should_throw_non_empty_error_and_revert(
 ) {
     let wallets = get_wallets(1).await;
-    let owners_list = wallets_to_identities(wallets[0..1].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..1].to_vec());
     let init_threshold = 1;
 
     // Deploy the multisig contract
@@ -643,7 +644,7 @@ async fn given_a_multisig_with_a_single_owner_when_trying_to_remove_that_owner_i
 async fn given_a_multisig_with_two_owners_and_a_threshold_of_two_when_trying_to_remove_an_owner_it_should_fail_with_verbose_error_and_revert(
 ) {
     let wallets = get_wallets(2).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let init_threshold = 2;
 
     // Deploy the multisig contract
@@ -728,3 +729,400 @@ async fn given_a_multisig_with_two_owners_and_a_threshold_of_two_when_trying_to_
 
     assert_eq!(new_owners, owners_before);
 }
+
+#[tokio::test]
+async fn given_a_multisig_with_max_owners_reached_when_swapping_an_owner_then_it_is_replaced_in_place(
+) {
+    let wallets = get_wallets(11).await;
+    let owners_list = wallets_to_owners(wallets[0..10].to_vec());
+    let init_threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 1
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    let old_owner = owners_list[3].identity.clone();
+    let new_owner = Identity::Address(Address::from(wallets[10].address()));
+
+    // Propose the swap
+    let response = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            3600,
+            call_parameters_swap_owner(old_owner.clone(), new_owner.clone()),
+        )
+        .call()
+        .await
+        .unwrap();
+
+    // Execute the tx because the threshold is 1
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(response.value)
+        .append_contract(contract_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Check the event
+    let logs = response.decode_logs_with_type::<OwnerSwapped>().unwrap();
+
+    assert_eq!(
+        logs,
+        vec![OwnerSwapped {
+            old_owner: old_owner.clone(),
+            new_owner: new_owner.clone(),
+            weight: 1,
+            config_epoch: 1,
+        }]
+    );
+
+    // Check the new owner took the position of the old one
+    let owners = deployer
+        .contract
+        .methods()
+        .get_owners()
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let mut expected_owners = owners_list.clone();
+    expected_owners[3] = Owner {
+        identity: new_owner.clone(),
+        weight: 1,
+    };
+    assert_eq!(owners, expected_owners);
+
+    let old_is_owner = deployer
+        .contract
+        .methods()
+        .is_owner(old_owner)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    assert!(!old_is_owner);
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_a_pending_tx_when_swapping_an_owner_that_approved_it_then_its_approval_is_dropped(
+) {
+    let wallets = get_wallets(4).await;
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+    let init_threshold = 2;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 2
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer from the owner that is going to be swapped
+    let old_owner_caller = get_multisig_caller(&contract_id, wallets[1].clone());
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let pending_tx_id = old_owner_caller
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Propose and approve the swap
+    let old_owner = owners_list[1].identity.clone();
+    let new_owner = Identity::Address(Address::from(wallets[3].address()));
+    let swap_tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            3600,
+            call_parameters_swap_owner(old_owner.clone(), new_owner.clone()),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let _ = get_multisig_caller(&contract_id, wallets[2].clone())
+        .contract
+        .methods()
+        .approve_tx(swap_tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Execute the swap
+    let _ = deployer
+        .contract
+        .methods()
+        .execute_tx(swap_tx_id)
+        .append_contract(contract_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Check that the approval of the old owner was dropped
+    let pending_tx = deployer
+        .contract
+        .methods()
+        .get_tx(pending_tx_id)
+        .call()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+    let old_owner_vote = deployer
+        .contract
+        .methods()
+        .get_tx_approval_by_owner(pending_tx_id, old_owner)
+        .call()
+        .await
+        .unwrap()
+        .value;
+    let new_owner_vote = deployer
+        .contract
+        .methods()
+        .get_tx_approval_by_owner(pending_tx_id, new_owner)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(pending_tx.approvals_count, 0);
+    assert_eq!(old_owner_vote, None);
+    assert_eq!(new_owner_vote, None);
+}
+
+#[tokio::test]
+async fn given_a_tx_approved_by_an_owner_when_that_owner_is_removed_then_its_approval_no_longer_counts_for_the_threshold(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+    let init_threshold = 2;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 2
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer from the owner that is going to be removed
+    let removed_owner_caller = get_multisig_caller(&contract_id, wallets[1].clone());
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let pending_tx_id = removed_owner_caller
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Propose, approve and execute the removal of that owner
+    let remove_tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            3600,
+            call_parameters_remove_owner(owners_list[1].identity.clone()),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let _ = get_multisig_caller(&contract_id, wallets[2].clone())
+        .contract
+        .methods()
+        .approve_tx(remove_tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    let _ = deployer
+        .contract
+        .methods()
+        .execute_tx(remove_tx_id)
+        .append_contract(contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Approve the pending transfer from a remaining owner
+    let _ = deployer
+        .contract
+        .methods()
+        .approve_tx(pending_tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Try to execute the transfer
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(pending_tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "ThresholdNotReached");
+
+    // Check that only the approval of the remaining owner is counted
+    let pending_tx = deployer
+        .contract
+        .methods()
+        .get_tx(pending_tx_id)
+        .call()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+
+    assert_eq!(pending_tx.approvals_count, 1);
+}
+
+#[tokio::test]
+async fn given_a_tx_approved_by_a_removed_owner_when_that_owner_is_added_again_then_its_old_approval_is_not_restored(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+    let init_threshold = 2;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 2
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer from the owner that is going to be removed
+    let removed_owner_caller = get_multisig_caller(&contract_id, wallets[1].clone());
+    let removed_owner = owners_list[1].identity.clone();
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let pending_tx_id = removed_owner_caller
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Remove the owner and add it again
+    let third_owner_caller = get_multisig_caller(&contract_id, wallets[2].clone());
+    for tx_parameters in [
+        call_parameters_remove_owner(removed_owner.clone()),
+        call_parameters_add_owner(removed_owner.clone(), 1),
+    ] {
+        let tx_id = deployer
+            .contract
+            .methods()
+            .propose_tx(
+                Identity::ContractId(contract_id.clone().into()),
+                3600,
+                tx_parameters,
+            )
+            .call()
+            .await
+            .unwrap()
+            .value;
+
+        let _ = third_owner_caller
+            .contract
+            .methods()
+            .approve_tx(tx_id)
+            .call()
+            .await
+            .unwrap();
+
+        let _ = deployer
+            .contract
+            .methods()
+            .execute_tx(tx_id)
+            .append_contract(contract_id.clone())
+            .call()
+            .await
+            .unwrap();
+    }
+
+    // Check that the approval cast before the removal is not counted
+    let approval = deployer
+        .contract
+        .methods()
+        .get_tx_approval_by_owner(pending_tx_id, removed_owner.clone())
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let pending_tx = deployer
+        .contract
+        .methods()
+        .get_tx(pending_tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+
+    assert_eq!(approval, None);
+    assert_eq!(pending_tx.approvals_count, 0);
+
+    // The owner can approve the transaction again
+    let _ = removed_owner_caller
+        .contract
+        .methods()
+        .approve_tx(pending_tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    let pending_tx = deployer
+        .contract
+        .methods()
+        .get_tx(pending_tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+
+    assert_eq!(pending_tx.approvals_count, 1);
+}
@@ -0,0 +1,122 @@
+use fuels::{accounts::wallet::WalletUnlocked, prelude::*};
+
+use crate::utils::predicate::{
+    fund_predicate, multisig_predicate, spend_from_predicate,
+    spend_from_predicate_with_duplicated_witness,
+};
+use crate::utils::setup::get_wallets;
+
+const PREDICATE_FUNDS: u64 = 1_000_000;
+const SPEND_AMOUNT: u64 = 500;
+
+#[tokio::test]
+async fn given_a_predicate_with_three_owners_and_threshold_two_when_two_owners_sign_then_the_coins_are_spent(
+) {
+    let wallets = get_wallets(3).await;
+    let provider = wallets[0].provider().unwrap();
+
+    // Configure and fund the predicate
+    let predicate = multisig_predicate(provider, &wallets[0..3], 2);
+    fund_predicate(&wallets[0], &predicate, PREDICATE_FUNDS).await;
+
+    let receiver_wallet = WalletUnlocked::new_random(None);
+
+    // Spend with the signatures of two owners
+    let response = spend_from_predicate(
+        &predicate,
+        &wallets[1..3],
+        receiver_wallet.address(),
+        SPEND_AMOUNT,
+    )
+    .await;
+
+    assert!(response.is_ok());
+
+    let receiver_balance = provider
+        .get_asset_balance(receiver_wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+
+    assert_eq!(receiver_balance, SPEND_AMOUNT);
+}
+
+#[tokio::test]
+async fn given_a_predicate_with_three_owners_and_threshold_two_when_only_one_owner_signs_then_the_spend_fails(
+) {
+    let wallets = get_wallets(3).await;
+    let provider = wallets[0].provider().unwrap();
+
+    // Configure and fund the predicate
+    let predicate = multisig_predicate(provider, &wallets[0..3], 2);
+    fund_predicate(&wallets[0], &predicate, PREDICATE_FUNDS).await;
+
+    let receiver_wallet = WalletUnlocked::new_random(None);
+
+    // Try to spend with the signature of a single owner
+    let response = spend_from_predicate(
+        &predicate,
+        &wallets[1..2],
+        receiver_wallet.address(),
+        SPEND_AMOUNT,
+    )
+    .await;
+
+    assert!(response.is_err());
+
+    let receiver_balance = provider
+        .get_asset_balance(receiver_wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+    let predicate_balance = provider
+        .get_asset_balance(predicate.address(), AssetId::BASE)
+        .await
+        .unwrap();
+
+    assert_eq!(receiver_balance, 0);
+    assert_eq!(predicate_balance, PREDICATE_FUNDS);
+}
+
+#[tokio::test]
+async fn given_a_predicate_with_threshold_two_when_an_owner_signs_twice_or_a_not_owner_signs_then_the_spend_fails(
+) {
+    let wallets = get_wallets(3).await;
+    let provider = wallets[0].provider().unwrap();
+
+    // Configure the predicate with only two owners and fund it
+    let predicate = multisig_predicate(provider, &wallets[0..2], 2);
+    fund_predicate(&wallets[0], &predicate, PREDICATE_FUNDS).await;
+
+    let receiver_wallet = WalletUnlocked::new_random(None);
+
+    // The same owner signing twice is only counted once
+    let response = spend_from_predicate_with_duplicated_witness(
+        &predicate,
+        &wallets[1],
+        receiver_wallet.address(),
+        SPEND_AMOUNT,
+    )
+    .await;
+
+    assert!(response
+        .unwrap_err()
+        .to_string()
+        .contains("PredicateVerificationFailed"));
+
+    // A signature from a wallet that is not an owner is not counted
+    let response = spend_from_predicate(
+        &predicate,
+        &wallets[1..3],
+        receiver_wallet.address(),
+        SPEND_AMOUNT,
+    )
+    .await;
+
+    assert!(response.is_err());
+
+    let receiver_balance = provider
+        .get_asset_balance(receiver_wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+
+    assert_eq!(receiver_balance, 0);
+}
@@ -0,0 +1,210 @@
+use fuels::prelude::*;
+use fuels::types::{ContractId, Identity};
+
+use crate::utils::abi::{MultisigConfigurables, MultisigProxy};
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{
+    call_parameters_set_proxy_target, deploy_multisig_with_configurables,
+    deploy_multisig_with_proxy, get_multisig_caller, get_wallets, transfer_parameters,
+    wallets_to_owners,
+};
+use crate::utils::validate_error;
+
+#[tokio::test]
+async fn given_a_multisig_behind_a_proxy_when_the_owners_upgrade_it_then_the_state_is_kept() {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 2;
+
+    // Deploy the multisig implementation and the proxy
+    let (proxy_contract_id, target_contract_id, deployer) =
+        deploy_multisig_with_proxy(&wallets[0]).await.unwrap();
+    let provider = deployer.wallet.provider().unwrap().clone();
+
+    // Call the multisig constructor through the proxy
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .append_contract(target_contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the proxy
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &proxy_contract_id,
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Propose a transfer that stays pending during the upgrade
+    let (receiver_wallet, receiver, tx_parameters) = transfer_parameters();
+    let transfer_tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, tx_parameters)
+        .append_contract(target_contract_id.clone())
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Deploy the new implementation
+    let configurables = MultisigConfigurables::default()
+        .with_MAX_TRANSACTIONS(10)
+        .unwrap();
+    let (new_target_contract_id, _) =
+        deploy_multisig_with_configurables(&wallets[0], configurables)
+            .await
+            .unwrap();
+
+    // The owners approve the upgrade
+    let second_owner = get_multisig_caller(&proxy_contract_id, wallets[1].clone());
+    let upgrade_tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(proxy_contract_id.clone().into()),
+            3600,
+            call_parameters_set_proxy_target(ContractId::from(&new_target_contract_id)),
+        )
+        .append_contract(target_contract_id.clone())
+        .call()
+        .await
+        .unwrap()
+        .value;
+    second_owner
+        .contract
+        .methods()
+        .approve_tx(upgrade_tx_id)
+        .append_contract(target_contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+    deployer
+        .contract
+        .methods()
+        .execute_tx(upgrade_tx_id)
+        .append_contract(target_contract_id.clone())
+        .append_contract(proxy_contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Check that the proxy points to the new implementation
+    let proxy_target = MultisigProxy::new(proxy_contract_id.clone(), wallets[0].clone())
+        .methods()
+        .proxy_target()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(
+        proxy_target,
+        Some(ContractId::from(&new_target_contract_id))
+    );
+
+    // Check that the state was kept by the new implementation
+    let owners = deployer
+        .contract
+        .methods()
+        .get_owners()
+        .append_contract(new_target_contract_id.clone())
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let current_threshold = deployer
+        .contract
+        .methods()
+        .get_threshold()
+        .append_contract(new_target_contract_id.clone())
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let active_tx_ids = deployer
+        .contract
+        .methods()
+        .get_active_tx_ids()
+        .append_contract(new_target_contract_id.clone())
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(owners, owners_list);
+    assert_eq!(current_threshold, threshold);
+    assert_eq!(active_tx_ids, vec![transfer_tx_id]);
+
+    // The pending transfer is approved and executed through the new implementation
+    second_owner
+        .contract
+        .methods()
+        .approve_tx(transfer_tx_id)
+        .append_contract(new_target_contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+    deployer
+        .contract
+        .methods()
+        .execute_tx(transfer_tx_id)
+        .append_contract(new_target_contract_id.clone())
+        .append_variable_outputs(1)
+        .call()
+        .await
+        .unwrap();
+
+    let receiver_balance = provider
+        .get_asset_balance(receiver_wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+    assert_eq!(receiver_balance, DEFAULT_TRANSFER_AMOUNT);
+}
+
+#[tokio::test]
+async fn given_a_multisig_behind_a_proxy_when_an_owner_sets_the_target_directly_then_it_reverts() {
+    let wallets = get_wallets(1).await;
+    let owners_list = wallets_to_owners(wallets[0..1].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig implementation and the proxy
+    let (proxy_contract_id, target_contract_id, deployer) =
+        deploy_multisig_with_proxy(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor through the proxy
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list)
+        .append_contract(target_contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // The target can only be changed through an approved proposal
+    let proxy = MultisigProxy::new(proxy_contract_id.clone(), wallets[0].clone());
+    let response = proxy
+        .methods()
+        .set_proxy_target(ContractId::from(&proxy_contract_id))
+        .call()
+        .await;
+
+    validate_error(response, "Unauthorized");
+
+    let proxy_target = proxy
+        .methods()
+        .proxy_target()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(proxy_target, Some(ContractId::from(&target_contract_id)));
+}
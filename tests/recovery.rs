@@ -0,0 +1,230 @@
+use fuels::prelude::*;
+use fuels::types::{bech32::Bech32ContractId, Identity};
+
+use crate::utils::abi::{Owner, RecoveryCancelled, RecoveryFinalized};
+use crate::utils::setup::{
+    advance_block_time, call_parameters_set_guardians, deploy_multisig, execute_self_call,
+    get_multisig_caller, get_wallets, wallets_to_owners, MultisigCaller,
+};
+use crate::utils::validate_error;
+
+const GUARDIAN_THRESHOLD: u64 = 2;
+const RECOVERY_DELAY: u64 = 3600;
+
+async fn setup_multisig_with_guardians(
+    wallets: &[WalletUnlocked],
+) -> (Bech32ContractId, MultisigCaller) {
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list)
+        .call()
+        .await
+        .unwrap();
+
+    // Set the guardians
+    let guardians = wallets[2..4]
+        .iter()
+        .map(|wallet| Identity::Address(wallet.address().into()))
+        .collect();
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_set_guardians(guardians, GUARDIAN_THRESHOLD, RECOVERY_DELAY),
+    )
+    .await;
+
+    (contract_id, deployer)
+}
+
+#[tokio::test]
+async fn given_a_recovery_approved_by_the_guardians_when_the_delay_has_passed_then_anyone_can_finalize_it(
+) {
+    let wallets = get_wallets(5).await;
+    let (contract_id, deployer) = setup_multisig_with_guardians(&wallets).await;
+    let provider = deployer.wallet.provider().unwrap().clone();
+
+    let first_guardian = get_multisig_caller(&contract_id, wallets[2].clone());
+    let second_guardian = get_multisig_caller(&contract_id, wallets[3].clone());
+    let new_owners = wallets_to_owners(wallets[4..5].to_vec());
+    let new_threshold = 1;
+
+    // The guardians start and approve the recovery
+    first_guardian
+        .contract
+        .methods()
+        .initiate_recovery(new_owners.clone(), new_threshold)
+        .call()
+        .await
+        .unwrap();
+    second_guardian
+        .contract
+        .methods()
+        .approve_recovery()
+        .call()
+        .await
+        .unwrap();
+
+    let recovery = deployer
+        .contract
+        .methods()
+        .get_recovery()
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+    assert_eq!(recovery.approvals, GUARDIAN_THRESHOLD);
+    assert!(recovery.approved_at.is_some());
+
+    // The recovery can't be finalized before the delay has passed
+    let new_owner = get_multisig_caller(&contract_id, wallets[4].clone());
+    let response = new_owner
+        .contract
+        .methods()
+        .finalize_recovery()
+        .call()
+        .await;
+    validate_error(response, "RecoveryDelayNotExpired");
+
+    advance_block_time(&provider, RECOVERY_DELAY as i64).await;
+
+    // Anyone can finalize the recovery after the delay
+    let response = new_owner
+        .contract
+        .methods()
+        .finalize_recovery()
+        .call()
+        .await
+        .unwrap();
+
+    // Check the event
+    let events = response
+        .decode_logs_with_type::<RecoveryFinalized>()
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].new_owners, new_owners);
+    assert_eq!(events[0].new_threshold, new_threshold);
+
+    // Check that the owners and the threshold were replaced
+    let owners = deployer
+        .contract
+        .methods()
+        .get_owners()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let threshold = deployer
+        .contract
+        .methods()
+        .get_threshold()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let recovery = deployer
+        .contract
+        .methods()
+        .get_recovery()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(
+        owners,
+        vec![Owner {
+            identity: Identity::Address(wallets[4].address().into()),
+            weight: 1,
+        }]
+    );
+    assert_eq!(threshold, new_threshold);
+    assert!(recovery.is_none());
+}
+
+#[tokio::test]
+async fn given_a_recovery_in_progress_when_an_owner_cancels_it_then_it_can_not_be_finalized() {
+    let wallets = get_wallets(5).await;
+    let (contract_id, deployer) = setup_multisig_with_guardians(&wallets).await;
+    let provider = deployer.wallet.provider().unwrap().clone();
+
+    let first_guardian = get_multisig_caller(&contract_id, wallets[2].clone());
+    let second_guardian = get_multisig_caller(&contract_id, wallets[3].clone());
+    let new_owners = wallets_to_owners(wallets[4..5].to_vec());
+
+    // The guardians start and approve the recovery
+    first_guardian
+        .contract
+        .methods()
+        .initiate_recovery(new_owners, 1)
+        .call()
+        .await
+        .unwrap();
+    second_guardian
+        .contract
+        .methods()
+        .approve_recovery()
+        .call()
+        .await
+        .unwrap();
+
+    // A guardian can't cancel the recovery
+    let response = first_guardian
+        .contract
+        .methods()
+        .cancel_recovery()
+        .call()
+        .await;
+    validate_error(response, "NotOwner");
+
+    // An owner cancels the recovery
+    let response = deployer
+        .contract
+        .methods()
+        .cancel_recovery()
+        .call()
+        .await
+        .unwrap();
+
+    // Check the event
+    let events = response
+        .decode_logs_with_type::<RecoveryCancelled>()
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![RecoveryCancelled {
+            recovery_id: 0,
+            cancelled_by: Identity::Address(wallets[0].address().into()),
+        }]
+    );
+
+    advance_block_time(&provider, RECOVERY_DELAY as i64).await;
+
+    // The recovery can't be finalized after the delay
+    let response = second_guardian
+        .contract
+        .methods()
+        .finalize_recovery()
+        .call()
+        .await;
+    validate_error(response, "NoRecoveryInProgress");
+
+    // Check that the owners were not changed
+    let owners = deployer
+        .contract
+        .methods()
+        .get_owners()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(owners, wallets_to_owners(wallets[0..2].to_vec()));
+}
@@ -0,0 +1,106 @@
+use fuels::prelude::*;
+
+use crate::utils::abi::{GasRefund, GasRefunded};
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{
+    deploy_multisig, get_multisig_caller, get_wallets, transfer_parameters, wallets_to_owners,
+};
+
+const MAX_REFUND: u64 = 100;
+
+#[tokio::test]
+async fn given_a_transaction_with_a_gas_refund_when_executed_then_the_executor_is_refunded_up_to_the_maximum(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+    let provider = deployer.wallet.provider().unwrap().clone();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer the funds for the transfer and the refund to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT + MAX_REFUND,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Propose a transfer refunding the gas to the executor
+    let gas_refund = GasRefund {
+        asset_id: AssetId::BASE,
+        gas_price: 1,
+        max_amount: MAX_REFUND,
+    };
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx_with_gas_refund(receiver, 3600, transaction_parameters, gas_refund.clone())
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Check the refund parameters are part of the transaction data
+    let tx = deployer
+        .contract
+        .methods()
+        .get_tx(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+
+    assert_eq!(tx.gas_refund, Some(gas_refund));
+
+    // Execute the transaction from the second owner
+    let executor = get_multisig_caller(&contract_id, wallets[1].clone());
+    let executor_balance_before = provider
+        .get_asset_balance(executor.wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+
+    let response = executor
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(2)
+        .call()
+        .await
+        .unwrap();
+
+    let executor_balance_after = provider
+        .get_asset_balance(executor.wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+
+    // Check the refund event
+    let events = response.decode_logs_with_type::<GasRefunded>().unwrap();
+    assert_eq!(events.len(), 1);
+
+    let refunded_amount = events[0].amount;
+    assert!(refunded_amount > 0);
+    assert!(refunded_amount <= MAX_REFUND);
+
+    // The test node has a gas price of 0, so the executor balance only changes by the refund
+    assert_eq!(
+        executor_balance_after,
+        executor_balance_before + refunded_amount
+    );
+}
@@ -0,0 +1,192 @@
+use fuels::prelude::*;
+use fuels::types::Identity;
+
+use crate::utils::abi::{CancellationReason, TransactionCancelled, TransactionStatus};
+use crate::utils::setup::{
+    advance_block_time, call_parameters_change_threshold, deploy_multisig, get_multisig_caller,
+    get_wallets, transfer_parameters, wallets_to_owners,
+};
+use crate::utils::validate_error;
+
+const TX_VALIDITY_DURATION: u64 = 100;
+
+#[tokio::test]
+async fn given_a_pending_transaction_when_it_expires_then_it_can_be_removed_with_the_expired_reason(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+    let threshold = 2;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+    let provider = deployer.wallet.provider().unwrap().clone();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, TX_VALIDITY_DURATION, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // The transaction can not be removed while it is valid
+    let response = deployer.contract.methods().remove_tx(tx_id).call().await;
+
+    assert!(response.is_err());
+    validate_error(response, "TransactionStillValid");
+
+    // Let the transaction expire
+    advance_block_time(&provider, TX_VALIDITY_DURATION as i64 + 1).await;
+
+    // Remove the transaction
+    let response = deployer
+        .contract
+        .methods()
+        .remove_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Check the event
+    let events = response
+        .decode_logs_with_type::<TransactionCancelled>()
+        .unwrap();
+
+    assert_eq!(
+        events,
+        vec![TransactionCancelled {
+            tx_id,
+            reason: CancellationReason::Expired,
+        }]
+    );
+
+    // Check the final status
+    let status = deployer
+        .contract
+        .methods()
+        .get_tx_status(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(status, Some(TransactionStatus::Expired));
+}
+
+#[tokio::test]
+async fn given_a_rejected_transaction_when_the_threshold_becomes_unreachable_then_it_can_be_removed_before_expiring(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+    let threshold = 2;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, TX_VALIDITY_DURATION, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // A single rejection still allows the threshold to be reached
+    let rejecter = get_multisig_caller(&contract_id, wallets[2].clone());
+    let _ = rejecter
+        .contract
+        .methods()
+        .reject_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Raise the threshold to the total weight, so the rejected transaction can no longer reach it
+    let change_threshold_tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            TX_VALIDITY_DURATION,
+            call_parameters_change_threshold(3),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let _ = get_multisig_caller(&contract_id, wallets[1].clone())
+        .contract
+        .methods()
+        .approve_tx(change_threshold_tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    let _ = deployer
+        .contract
+        .methods()
+        .execute_tx(change_threshold_tx_id)
+        .append_contract(contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Remove the transaction without waiting for it to expire
+    let response = deployer
+        .contract
+        .methods()
+        .remove_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Check the event
+    let events = response
+        .decode_logs_with_type::<TransactionCancelled>()
+        .unwrap();
+
+    assert_eq!(
+        events,
+        vec![TransactionCancelled {
+            tx_id,
+            reason: CancellationReason::ThresholdUnreachable,
+        }]
+    );
+
+    // Check the final status
+    let status = deployer
+        .contract
+        .methods()
+        .get_tx_status(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(status, Some(TransactionStatus::Cancelled));
+}
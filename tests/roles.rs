@@ -0,0 +1,251 @@
+use fuels::prelude::*;
+use fuels::types::Identity;
+
+use crate::utils::abi::Roles;
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{
+    call_parameters_set_roles, deploy_multisig, execute_self_call, get_multisig_caller,
+    get_wallets, transfer_parameters, wallets_to_owners,
+};
+use crate::utils::validate_error;
+
+#[tokio::test]
+async fn given_a_multisig_with_a_bot_with_proposer_and_executor_roles_when_the_bot_proposes_and_executes_then_the_transfer_is_performed(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            deployer.contract.contract_id(),
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Grant the proposer and executor roles to a bot that is not an owner
+    let bot_roles = Roles {
+        proposer: true,
+        approver: false,
+        rejecter: false,
+        executor: true,
+    };
+    let bot_identity = Identity::Address(wallets[2].address().into());
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_set_roles(bot_identity.clone(), bot_roles.clone()),
+    )
+    .await;
+
+    let roles = deployer
+        .contract
+        .methods()
+        .get_roles(bot_identity)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(roles, Some(bot_roles));
+
+    // The bot proposes a transfer
+    let bot = get_multisig_caller(&contract_id, wallets[2].clone());
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = bot
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // The bot cannot approve its own proposal
+    let response = bot.contract.methods().approve_tx(tx_id).call().await;
+
+    assert!(response.is_err());
+    validate_error(response, "NotApprover");
+
+    // An owner approves the proposal
+    let _ = deployer
+        .contract
+        .methods()
+        .approve_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // The bot executes the transfer
+    let response = bot
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_an_auditor_with_only_the_rejecter_role_when_it_tries_other_actions_then_should_throw_missing_role_errors(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Restrict the second owner to the rejecter role
+    let auditor_roles = Roles {
+        proposer: false,
+        approver: false,
+        rejecter: true,
+        executor: false,
+    };
+    execute_self_call(
+        &deployer,
+        &contract_id,
+        call_parameters_set_roles(owners_list[1].identity.clone(), auditor_roles),
+    )
+    .await;
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver.clone(), 3600, transaction_parameters.clone())
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let auditor = get_multisig_caller(&contract_id, wallets[1].clone());
+
+    // The auditor cannot propose
+    let response = auditor
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await;
+
+    assert!(response.is_err());
+    validate_error(response, "NotProposer");
+
+    // The auditor cannot approve
+    let response = auditor.contract.methods().approve_tx(tx_id).call().await;
+
+    assert!(response.is_err());
+    validate_error(response, "NotApprover");
+
+    // The auditor cannot execute
+    let response = auditor
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    assert!(response.is_err());
+    validate_error(response, "NotExecutor");
+
+    // The auditor can reject
+    let response = auditor.contract.methods().reject_tx(tx_id).call().await;
+
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn given_a_multisig_when_granting_the_rejecter_role_to_an_identity_that_is_not_owner_then_should_throw_not_owner(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list)
+        .call()
+        .await
+        .unwrap();
+
+    // Propose to grant the rejecter role to an identity that is not an owner
+    let auditor = Identity::Address(wallets[2].address().into());
+    let auditor_roles = Roles {
+        proposer: false,
+        approver: false,
+        rejecter: true,
+        executor: false,
+    };
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            3600,
+            call_parameters_set_roles(auditor.clone(), auditor_roles),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Execute the tx
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(contract_id.clone())
+        .call()
+        .await;
+
+    validate_error(response, "NotOwner");
+
+    // Check that the identity was not granted any role
+    let roles = deployer
+        .contract
+        .methods()
+        .get_roles(auditor)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(roles, None);
+}
@@ -1,13 +1,15 @@
 use fuels::prelude::*;
+use fuels::types::{ContractId, Identity};
 
-use crate::utils::setup::{deploy_multisig, get_wallets, wallets_to_identities};
+use crate::utils::abi::{ExecutorPolicy, Roles};
+use crate::utils::setup::{deploy_multisig, get_wallets, wallets_to_owners};
 use crate::utils::validate_error;
 
 #[tokio::test]
 async fn given_a_multisig_and_a_not_owner_account_when_try_to_change_threshold_from_it_then_should_throw_unauthorized(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let init_threshold = 1;
 
     // Deploy the multisig contract
@@ -36,7 +38,7 @@ async fn given_a_multisig_and_a_not_owner_account_when_try_to_change_threshold_f
 async fn given_a_multisig_and_a_not_owner_account_when_try_to_add_owner_from_it_then_should_throw_unauthorized(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let init_threshold = 1;
 
     // Deploy the multisig contract
@@ -55,7 +57,7 @@ async fn given_a_multisig_and_a_not_owner_account_when_try_to_add_owner_from_it_
     let response = deployer
         .contract
         .methods()
-        .add_owner(owners_list[0].clone())
+        .add_owner(owners_list[0].identity.clone(), 1)
         .call()
         .await;
 
@@ -70,7 +72,7 @@ async fn given_a_multisig_and_a_not_owner_account_when_try_to_add_owner_from_it_
 async fn given_a_multisig_and_a_not_owner_account_when_try_to_remove_owner_from_it_then_should_throw_unauthorized(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let init_threshold = 1;
 
     // Deploy the multisig contract
@@ -89,7 +91,298 @@ async fn given_a_multisig_and_a_not_owner_account_when_try_to_remove_owner_from_
     let response = deployer
         .contract
         .methods()
-        .remove_owner(owners_list[0].clone())
+        .remove_owner(owners_list[0].identity.clone())
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "Unauthorized");
+}
+
+#[tokio::test]
+async fn given_a_multisig_and_a_not_owner_account_when_try_to_swap_owner_from_it_then_should_throw_unauthorized(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let init_threshold = 1;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 1
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Try to call swap_owner with an external account
+    let response = deployer
+        .contract
+        .methods()
+        .swap_owner(
+            owners_list[0].identity.clone(),
+            Identity::Address(Address::from(wallets[2].address())),
+        )
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "Unauthorized");
+}
+
+#[tokio::test]
+async fn given_a_multisig_and_a_not_owner_account_when_try_to_update_config_from_it_then_should_throw_unauthorized(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let init_threshold = 1;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 1
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Try to call update_config with an external account
+    let response = deployer
+        .contract
+        .methods()
+        .update_config(
+            wallets_to_owners(wallets[2..3].to_vec()),
+            vec![owners_list[0].identity.clone()],
+            None,
+        )
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "Unauthorized");
+}
+
+#[tokio::test]
+async fn given_a_multisig_and_a_not_owner_account_when_try_to_set_roles_from_it_then_should_throw_unauthorized(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let init_threshold = 1;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 1
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    let roles = Roles {
+        proposer: true,
+        approver: true,
+        rejecter: true,
+        executor: true,
+    };
+
+    // Try to call set_roles with an external account
+    let response = deployer
+        .contract
+        .methods()
+        .set_roles(Identity::Address(wallets[2].address().into()), roles)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "Unauthorized");
+}
+
+#[tokio::test]
+async fn given_a_multisig_and_a_not_owner_account_when_try_to_set_allowance_from_it_then_should_throw_unauthorized(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let init_threshold = 1;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 1
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Try to call set_allowance with an external account
+    let response = deployer
+        .contract
+        .methods()
+        .set_allowance(
+            Identity::Address(wallets[2].address().into()),
+            AssetId::BASE,
+            100,
+            0,
+        )
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "Unauthorized");
+}
+
+#[tokio::test]
+async fn given_a_multisig_and_a_not_owner_account_when_try_to_set_execution_delay_from_it_then_should_throw_unauthorized(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let init_threshold = 1;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 1
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Try to call set_execution_delay with an external account
+    let response = deployer
+        .contract
+        .methods()
+        .set_execution_delay(0)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "Unauthorized");
+}
+
+#[tokio::test]
+async fn given_a_multisig_and_a_not_owner_account_when_try_to_set_executor_policy_from_it_then_should_throw_unauthorized(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let init_threshold = 1;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 1
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Try to call set_executor_policy with an external account
+    let response = deployer
+        .contract
+        .methods()
+        .set_executor_policy(ExecutorPolicy::Anyone)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "Unauthorized");
+}
+
+#[tokio::test]
+async fn given_a_multisig_and_a_not_owner_account_when_try_to_enable_module_from_it_then_should_throw_unauthorized(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let init_threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 1
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Try to call enable_module with an external account
+    let response = deployer
+        .contract
+        .methods()
+        .enable_module(ContractId::from(&contract_id))
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "Unauthorized");
+}
+
+#[tokio::test]
+async fn given_a_multisig_and_a_not_owner_account_when_try_to_disable_module_from_it_then_should_throw_unauthorized(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let init_threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor with threshold 1
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(init_threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Try to call disable_module with an external account
+    let response = deployer
+        .contract
+        .methods()
+        .disable_module(ContractId::from(&contract_id))
         .call()
         .await;
 
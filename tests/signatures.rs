@@ -0,0 +1,328 @@
+use fuels::prelude::*;
+use fuels::types::{bech32::Bech32ContractId, Bytes32, Identity, U256};
+
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{
+    deploy_multisig, get_multisig_caller, get_wallets, transfer_parameters, wallets_to_owners,
+    MultisigCaller,
+};
+use crate::utils::signatures::{compute_tx_hash, sign_tx_hash};
+use crate::utils::validate_error;
+
+async fn get_tx_hash_to_sign(
+    deployer: &MultisigCaller,
+    contract_id: &Bech32ContractId,
+    tx_id: U256,
+) -> Bytes32 {
+    let tx = deployer
+        .contract
+        .methods()
+        .get_tx(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+    let config_epoch = deployer
+        .contract
+        .methods()
+        .get_config_epoch()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let signature_nonce = deployer
+        .contract
+        .methods()
+        .get_tx_signature_nonce(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    compute_tx_hash(contract_id, &tx, config_epoch, signature_nonce)
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_a_proposed_transfer_when_a_relayer_submits_the_owners_signatures_then_the_threshold_is_reached(
+) {
+    let wallets = get_wallets(4).await;
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+    let threshold = 3;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            deployer.contract.contract_id(),
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Propose a transfer tx
+    let (receiver_wallet, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Compute the proposal hash off-chain and check it matches the contract one
+    let tx_hash = get_tx_hash_to_sign(&deployer, &contract_id, tx_id).await;
+
+    let contract_tx_hash = deployer
+        .contract
+        .methods()
+        .get_tx_hash(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+
+    assert_eq!(contract_tx_hash.0, *tx_hash);
+
+    // Sign the proposal with the remaining owners
+    let mut signatures = vec![];
+    for wallet in &wallets[1..3] {
+        signatures.push(sign_tx_hash(wallet, tx_hash).await);
+    }
+
+    // Submit the signatures from a relayer that is not an owner
+    let relayer = get_multisig_caller(&contract_id, wallets[3].clone());
+    let response = relayer
+        .contract
+        .methods()
+        .approve_tx_with_signatures(tx_id, signatures)
+        .call()
+        .await;
+
+    assert!(response.is_ok());
+
+    // Check the approvals
+    let tx = deployer
+        .contract
+        .methods()
+        .get_tx(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+
+    assert_eq!(tx.approvals_count, 3);
+
+    // Execute the transfer tx
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    assert!(response.is_ok());
+
+    let receiver_balance = deployer
+        .wallet
+        .provider()
+        .unwrap()
+        .get_asset_balance(receiver_wallet.address(), AssetId::BASE)
+        .await
+        .unwrap();
+
+    assert_eq!(receiver_balance, DEFAULT_TRANSFER_AMOUNT);
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_a_proposed_transfer_when_a_not_owner_signature_is_submitted_then_should_throw_not_owner(
+) {
+    let wallets = get_wallets(4).await;
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+    let threshold = 3;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer tx
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let tx_hash = get_tx_hash_to_sign(&deployer, &contract_id, tx_id).await;
+
+    // Sign the proposal with an owner and a wallet that is not an owner
+    let signatures = vec![
+        sign_tx_hash(&wallets[1], tx_hash).await,
+        sign_tx_hash(&wallets[3], tx_hash).await,
+    ];
+
+    let response = deployer
+        .contract
+        .methods()
+        .approve_tx_with_signatures(tx_id, signatures)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "NotOwner");
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_a_proposed_transfer_when_the_proposer_signature_is_submitted_then_should_throw_already_voted(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+    let threshold = 3;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer tx
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let tx_hash = get_tx_hash_to_sign(&deployer, &contract_id, tx_id).await;
+
+    // The proposer already approved the tx when proposing it
+    let signatures = vec![sign_tx_hash(&wallets[0], tx_hash).await];
+
+    let response = deployer
+        .contract
+        .methods()
+        .approve_tx_with_signatures(tx_id, signatures)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "AlreadyVoted");
+}
+
+#[tokio::test]
+async fn given_a_vote_approved_with_a_signature_when_the_owner_revokes_it_then_the_signature_can_not_be_replayed(
+) {
+    let wallets = get_wallets(4).await;
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+    let threshold = 3;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer tx
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // A relayer submits the signature of the second owner
+    let tx_hash = get_tx_hash_to_sign(&deployer, &contract_id, tx_id).await;
+    let signatures = vec![sign_tx_hash(&wallets[1], tx_hash).await];
+    let relayer = get_multisig_caller(&contract_id, wallets[3].clone());
+    relayer
+        .contract
+        .methods()
+        .approve_tx_with_signatures(tx_id, signatures.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // The second owner revokes its approval
+    get_multisig_caller(&contract_id, wallets[1].clone())
+        .contract
+        .methods()
+        .revoke_vote(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // The relayer tries to restore the approval with the same signature
+    let response = relayer
+        .contract
+        .methods()
+        .approve_tx_with_signatures(tx_id, signatures)
+        .call()
+        .await;
+
+    // The signature no longer recovers the owner
+    validate_error(response, "NotOwner");
+
+    let approval = deployer
+        .contract
+        .methods()
+        .get_tx_approval_by_owner(tx_id, Identity::Address(wallets[1].address().into()))
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(approval, None);
+}
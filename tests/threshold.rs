@@ -3,7 +3,7 @@ use fuels::types::Identity;
 
 use crate::utils::setup::{
     call_parameters_change_threshold, deploy_multisig, get_multisig_caller, get_wallets,
-    wallets_to_identities,
+    wallets_to_owners,
 };
 use crate::utils::validate_error;
 
@@ -11,7 +11,7 @@ use crate::utils::validate_error;
 async fn given_a_multisig_with_two_owners_a_threshold_of_one_when_propose_to_increment_it_should_be_changed_to_the_new_value(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let init_threshold = 1;
 
     // Deploy the multisig contract
@@ -84,7 +84,7 @@ async fn given_a_multisig_with_two_owners_a_threshold_of_one_when_propose_to_inc
 async fn given_a_multisig_with_4_owners_and_a_threshold_of_3_when_trying_to_set_threshold_to_4_it_shoudl_be_changed_as_proposed(
 ) {
     let wallets = get_wallets(4).await;
-    let owners_list = wallets_to_identities(wallets[0..4].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..4].to_vec());
     let init_threshold = 3;
 
     // Deploy the multisig contract
@@ -171,7 +171,7 @@ async fn given_a_multisig_with_4_owners_and_a_threshold_of_3_when_trying_to_set_
 #[tokio::test]
 async fn given_a_multisig_when_trying_to_set_threshold_to_zero_it_should_fail_and_throw_error() {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let init_threshold = 1;
 
     // Deploy the multisig contract
@@ -248,7 +248,7 @@ async fn given_a_multisig_when_trying_to_set_threshold_to_zero_it_should_fail_an
 async fn given_a_multisig_of_2_owners_and_threshold_of_1_when_trying_to_set_threshold_to_3_it_should_fail_and_throw_error(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let init_threshold = 1;
 
     // Deploy the multisig contract
@@ -0,0 +1,394 @@
+use fuels::prelude::*;
+use fuels::types::{bech32::Bech32ContractId, Identity, U256};
+
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{
+    advance_block_time, call_parameters_change_threshold, call_parameters_set_execution_delay,
+    deploy_multisig, get_multisig_caller, get_wallets, transfer_parameters, wallets_to_owners,
+    MultisigCaller,
+};
+use crate::utils::validate_error;
+
+const EXECUTION_DELAY: u64 = 3600;
+
+#[tokio::test]
+async fn given_a_multisig_with_an_execution_delay_when_a_transaction_reaches_the_threshold_then_it_can_only_be_executed_after_the_delay(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 2;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+    let provider = deployer.wallet.provider().unwrap().clone();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            deployer.contract.contract_id(),
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    let second_owner = get_multisig_caller(&contract_id, wallets[1].clone());
+
+    // Set the execution delay, which does not apply to this proposal yet
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            EXECUTION_DELAY * 4,
+            call_parameters_set_execution_delay(EXECUTION_DELAY),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+    let _ = second_owner
+        .contract
+        .methods()
+        .approve_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+    let _ = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+
+    let execution_delay = deployer
+        .contract
+        .methods()
+        .get_execution_delay()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(execution_delay, EXECUTION_DELAY);
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, EXECUTION_DELAY * 4, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // The transfer is not queued until the threshold is reached
+    let queued_at = deployer
+        .contract
+        .methods()
+        .get_tx_queued_at(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert!(queued_at.is_none());
+
+    // The approval reaches the threshold and starts the execution delay
+    let _ = second_owner
+        .contract
+        .methods()
+        .approve_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    let queued_at = deployer
+        .contract
+        .methods()
+        .get_tx_queued_at(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert!(queued_at.is_some());
+
+    // Try to execute the transfer right away
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    assert!(response.is_err());
+    validate_error(response, "TimelockNotExpired");
+
+    // Try to execute the transfer before the delay has passed
+    advance_block_time(&provider, EXECUTION_DELAY as i64 / 2).await;
+
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    assert!(response.is_err());
+    validate_error(response, "TimelockNotExpired");
+
+    // Execute the transfer once the delay has passed
+    advance_block_time(&provider, EXECUTION_DELAY as i64).await;
+
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    assert!(response.is_ok());
+}
+
+async fn setup_multisig_with_execution_delay(
+    wallets: &[WalletUnlocked],
+    threshold: u64,
+) -> (Bech32ContractId, MultisigCaller) {
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list)
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // Set the execution delay
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            EXECUTION_DELAY * 4,
+            call_parameters_set_execution_delay(EXECUTION_DELAY),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+    approve_tx(&contract_id, &wallets[1..threshold as usize], tx_id).await;
+    let _ = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+
+    (contract_id, deployer)
+}
+
+async fn approve_tx(contract_id: &Bech32ContractId, owners: &[WalletUnlocked], tx_id: U256) {
+    for owner in owners {
+        let _ = get_multisig_caller(contract_id, owner.clone())
+            .contract
+            .methods()
+            .approve_tx(tx_id)
+            .call()
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_an_execution_delay_when_the_threshold_is_lowered_then_the_transactions_that_reach_it_can_be_executed_after_the_delay(
+) {
+    let wallets = get_wallets(3).await;
+    let (contract_id, deployer) = setup_multisig_with_execution_delay(&wallets, 2).await;
+    let provider = deployer.wallet.provider().unwrap().clone();
+
+    // Propose a transfer that does not reach the threshold
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let transfer_tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, EXECUTION_DELAY * 4, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Lower the threshold, so the transfer reaches it
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            EXECUTION_DELAY * 4,
+            call_parameters_change_threshold(1),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+    approve_tx(&contract_id, &wallets[1..2], tx_id).await;
+    advance_block_time(&provider, EXECUTION_DELAY as i64).await;
+    let _ = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // The transfer is queued since the threshold changed
+    let queued_at = deployer
+        .contract
+        .methods()
+        .get_tx_queued_at(transfer_tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert!(queued_at.is_some());
+
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(transfer_tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    validate_error(response, "TimelockNotExpired");
+
+    // Execute the transfer once the delay has passed
+    advance_block_time(&provider, EXECUTION_DELAY as i64).await;
+
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(transfer_tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_an_execution_delay_when_the_threshold_is_raised_then_the_delay_starts_again_when_a_transaction_reaches_it(
+) {
+    let wallets = get_wallets(3).await;
+    let (contract_id, deployer) = setup_multisig_with_execution_delay(&wallets, 1).await;
+    let provider = deployer.wallet.provider().unwrap().clone();
+
+    // Propose a transfer, which is queued right away
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let transfer_tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, EXECUTION_DELAY * 4, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Raise the threshold, so the transfer is no longer approved
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            EXECUTION_DELAY * 4,
+            call_parameters_change_threshold(2),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+    advance_block_time(&provider, EXECUTION_DELAY as i64).await;
+    let _ = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+
+    let queued_at = deployer
+        .contract
+        .methods()
+        .get_tx_queued_at(transfer_tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert!(queued_at.is_none());
+
+    // The transfer reaches the new threshold, which starts the delay again
+    approve_tx(&contract_id, &wallets[1..2], transfer_tx_id).await;
+
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(transfer_tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    validate_error(response, "TimelockNotExpired");
+
+    // Execute the transfer once the delay has passed
+    advance_block_time(&provider, EXECUTION_DELAY as i64).await;
+
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(transfer_tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    assert!(response.is_ok());
+}
@@ -1,10 +1,12 @@
 use fuels::prelude::*;
 use fuels::types::Identity;
 
+use crate::utils::abi::MultisigConfigurables;
 use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
 use crate::utils::setup::{
-    base_asset_contract_id, call_parameters, deploy_counter, deploy_multisig, get_multisig_caller,
-    get_wallets, transfer_parameters, wallets_to_identities,
+    base_asset_contract_id, call_parameters, deploy_counter, deploy_multisig,
+    deploy_multisig_with_configurables, get_multisig_caller, get_wallets, transfer_parameters,
+    wallets_to_owners,
 };
 use crate::utils::validate_error;
 
@@ -12,7 +14,7 @@ use crate::utils::validate_error;
 async fn given_a_multisig_with_a_proposed_transaction_when_threshold_is_reached_and_the_transaction_executed_then_the_contract_is_called_as_expected(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let threshold = 1;
 
     // Deploy the counter contract
@@ -97,7 +99,7 @@ async fn given_a_multisig_with_a_proposed_transaction_when_threshold_is_reached_
 async fn given_a_multisig_with_a_proposed_transfer_when_threshold_is_reached_and_the_transaction_executed_then_the_transfer_is_performed(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let threshold = 1;
 
     // Deploy the contract
@@ -189,13 +191,21 @@ async fn given_a_multisig_with_a_proposed_transfer_when_threshold_is_reached_and
 }
 
 #[tokio::test]
-async fn given_a_multisig_when_try_to_propose_a_tx_then_is_possible_until_max_tx_reached() {
+async fn given_a_multisig_with_max_transactions_configured_when_try_to_propose_a_tx_then_is_possible_until_max_tx_reached(
+) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let threshold = 1;
 
-    // Deploy the contract
-    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+    const MAX_TRANSACTIONS: u64 = 10;
+
+    // Deploy the contract with a limit of active transactions
+    let configurables = MultisigConfigurables::default()
+        .with_MAX_TRANSACTIONS(MAX_TRANSACTIONS)
+        .unwrap();
+    let (_, deployer) = deploy_multisig_with_configurables(&wallets[0], configurables)
+        .await
+        .unwrap();
 
     // Call the constructor
     let _ = deployer
@@ -209,8 +219,6 @@ async fn given_a_multisig_when_try_to_propose_a_tx_then_is_possible_until_max_tx
     // Get transfer parameters
     let (_, receiver, transaction_parameters) = transfer_parameters();
 
-    const MAX_TRANSACTIONS: u64 = 10;
-
     // Propose transfer txs until MAX_TRANSACTIONS is reached
     for _ in 0..MAX_TRANSACTIONS {
         deployer
@@ -269,7 +277,7 @@ async fn given_a_multisig_when_try_to_propose_a_tx_then_is_possible_until_max_tx
 async fn given_a_multisig_when_try_to_propose_a_tx_from_a_not_owner_account_then_should_throw_not_owner(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let threshold = 1;
 
     // Deploy the contract
@@ -308,7 +316,7 @@ async fn given_a_multisig_when_try_to_propose_a_tx_from_a_not_owner_account_then
 async fn given_a_multisig_with_not_enough_amount_when_try_to_transfer_then_should_throw_insufficient_asset_amount(
 ) {
     let wallets = get_wallets(3).await;
-    let owners_list = wallets_to_identities(wallets[0..2].to_vec());
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
     let threshold = 1;
 
     // Deploy the contract
@@ -389,3 +397,114 @@ async fn given_a_multisig_with_not_enough_amount_when_try_to_transfer_then_shoul
     assert_eq!(final_contract_balance, 0);
     assert_eq!(final_receiver_balance, 0);
 }
+
+#[tokio::test]
+async fn given_a_multisig_without_max_transactions_when_proposing_many_txs_and_removing_them_out_of_order_then_the_active_txs_are_consistent(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 2;
+
+    // Deploy the contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Get transfer parameters
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+
+    const TRANSACTIONS: u64 = 25;
+
+    // Propose far more txs than the previous limit of 10
+    let mut proposed_tx_ids = vec![];
+    for _ in 0..TRANSACTIONS {
+        let tx_id = deployer
+            .contract
+            .methods()
+            .propose_tx(receiver.clone(), 3600, transaction_parameters.clone())
+            .call()
+            .await
+            .unwrap()
+            .value;
+        proposed_tx_ids.push(tx_id);
+    }
+
+    // Cancel some of them out of order
+    let cancelled_tx_ids = vec![
+        proposed_tx_ids[3],
+        proposed_tx_ids[0],
+        proposed_tx_ids[24],
+        proposed_tx_ids[12],
+        proposed_tx_ids[23],
+    ];
+    for tx_id in &cancelled_tx_ids {
+        deployer
+            .contract
+            .methods()
+            .cancel_tx(*tx_id)
+            .call()
+            .await
+            .unwrap();
+    }
+
+    // Check the active txs
+    let mut active_tx_ids = deployer
+        .contract
+        .methods()
+        .get_active_tx_ids()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    let mut expected_tx_ids: Vec<_> = proposed_tx_ids
+        .into_iter()
+        .filter(|tx_id| !cancelled_tx_ids.contains(tx_id))
+        .collect();
+
+    // Collect the active txs page by page
+    const PAGE_SIZE: u64 = 7;
+    let mut paged_tx_ids = vec![];
+    let mut offset = 0;
+    loop {
+        let page = deployer
+            .contract
+            .methods()
+            .get_active_tx_ids_page(offset, PAGE_SIZE)
+            .simulate()
+            .await
+            .unwrap()
+            .value;
+        let txs_page = deployer
+            .contract
+            .methods()
+            .get_txs_page(offset, PAGE_SIZE)
+            .simulate()
+            .await
+            .unwrap()
+            .value;
+
+        assert!(page.len() as u64 <= PAGE_SIZE);
+        assert_eq!(txs_page.iter().map(|tx| tx.tx_id).collect::<Vec<_>>(), page);
+
+        if page.is_empty() {
+            break;
+        }
+        paged_tx_ids.extend(page);
+        offset += PAGE_SIZE;
+    }
+
+    assert_eq!(paged_tx_ids, active_tx_ids);
+
+    active_tx_ids.sort();
+    expected_tx_ids.sort();
+
+    assert_eq!(active_tx_ids, expected_tx_ids);
+}
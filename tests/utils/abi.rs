@@ -8,5 +8,25 @@ abigen!(
     Contract(
         name = "Counter",
         abi = "./utils/test-contracts/counter/out/debug/counter-abi.json"
+    ),
+    Contract(
+        name = "Reverter",
+        abi = "./utils/test-contracts/reverter/out/debug/reverter-abi.json"
+    ),
+    Contract(
+        name = "Module",
+        abi = "./utils/test-contracts/module/out/debug/module-abi.json"
+    ),
+    Contract(
+        name = "Guard",
+        abi = "./utils/test-contracts/guard/out/debug/guard-abi.json"
+    ),
+    Contract(
+        name = "MultisigProxy",
+        abi = "../multisig-proxy/out/debug/multisig-proxy-abi.json"
+    ),
+    Predicate(
+        name = "MultisigPredicate",
+        abi = "../multisig-predicate/out/debug/multisig-predicate-abi.json"
     )
 );
@@ -1,6 +1,8 @@
 pub mod abi;
 pub mod constants;
+pub mod predicate;
 pub mod setup;
+pub mod signatures;
 
 use fuels::{
     programs::call_response::FuelCallResponse,
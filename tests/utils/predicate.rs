@@ -0,0 +1,102 @@
+use super::abi::*;
+use fuels::{
+    accounts::{predicate::Predicate, wallet::WalletUnlocked, Account},
+    prelude::{Address, AssetId, Error, Provider, TxPolicies},
+    types::{
+        bech32::Bech32Address,
+        transaction::Transaction,
+        transaction_builders::{BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder},
+        tx_status::TxStatus,
+    },
+};
+
+/// Maximum number of owners supported by the predicate.
+pub const PREDICATE_MAX_OWNERS: usize = 10;
+
+/// Witness limit of the transactions that append witnesses after being built.
+const WITNESS_LIMIT: u64 = 10_000;
+
+/// Loads the multisig predicate configured with the given owners and threshold.
+pub fn multisig_predicate(provider: &Provider, owners: &[WalletUnlocked], threshold: u64) -> Predicate {
+    let mut owner_addresses = [Address::zeroed(); PREDICATE_MAX_OWNERS];
+    for (i, owner) in owners.iter().enumerate() {
+        owner_addresses[i] = Address::from(owner.address());
+    }
+
+    let configurables = MultisigPredicateConfigurables::default()
+        .with_OWNERS(owner_addresses)
+        .unwrap()
+        .with_THRESHOLD(threshold)
+        .unwrap();
+
+    Predicate::load_from("../multisig-predicate/out/debug/multisig-predicate.bin")
+        .unwrap()
+        .with_provider(provider.clone())
+        .with_configurables(configurables)
+}
+
+/// Sends `amount` of the base asset from `funder` to the predicate address.
+pub async fn fund_predicate(funder: &WalletUnlocked, predicate: &Predicate, amount: u64) {
+    funder
+        .transfer(predicate.address(), amount, AssetId::BASE, TxPolicies::default())
+        .await
+        .unwrap();
+}
+
+/// Spends `amount` of the base asset held by the predicate, adding a witness signed by each of the `signers`.
+pub async fn spend_from_predicate(
+    predicate: &Predicate,
+    signers: &[WalletUnlocked],
+    receiver: &Bech32Address,
+    amount: u64,
+) -> Result<TxStatus, Error> {
+    let provider = predicate.provider().unwrap();
+
+    let inputs = predicate
+        .get_asset_inputs_for_amount(AssetId::BASE, amount, None)
+        .await?;
+    let outputs = predicate.get_asset_outputs_for_amount(receiver, AssetId::BASE, amount);
+
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+
+    // Every signer adds its signature over the transaction id as a witness
+    for signer in signers {
+        tb.add_signer(signer.clone())?;
+    }
+
+    predicate.adjust_for_fee(&mut tb, amount).await?;
+
+    let tx = tb.build(provider).await?;
+    provider.send_transaction_and_await_commit(tx).await
+}
+
+/// Spends `amount` of the base asset held by the predicate with the witness of `signer` repeated twice.
+/// The copy is appended after the transaction is built, so the predicate itself has to reject the duplicate.
+pub async fn spend_from_predicate_with_duplicated_witness(
+    predicate: &Predicate,
+    signer: &WalletUnlocked,
+    receiver: &Bech32Address,
+    amount: u64,
+) -> Result<TxStatus, Error> {
+    let provider = predicate.provider().unwrap();
+
+    let inputs = predicate
+        .get_asset_inputs_for_amount(AssetId::BASE, amount, None)
+        .await?;
+    let outputs = predicate.get_asset_outputs_for_amount(receiver, AssetId::BASE, amount);
+
+    // Leave room for the duplicated witness
+    let tx_policies = TxPolicies::default().with_witness_limit(WITNESS_LIMIT);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+    tb.add_signer(signer.clone())?;
+
+    predicate.adjust_for_fee(&mut tb, amount).await?;
+
+    let mut tx = tb.build(provider).await?;
+
+    // The transaction id does not cover the witnesses, so the copy is still a valid signature
+    let witness = tx.witnesses().last().unwrap().clone();
+    tx.append_witness(witness)?;
+
+    provider.send_transaction_and_await_commit(tx).await
+}
@@ -1,11 +1,16 @@
 use super::abi::*;
 use super::constants::{DEFAULT_FORWARDED_GAS, DEFAULT_TRANSFER_AMOUNT};
+use chrono::Duration;
 use fuels::{
     accounts::wallet::WalletUnlocked,
     core::codec::{calldata, encode_fn_selector},
-    prelude::{Address, AssetId, Contract, Error, LoadConfiguration, TxPolicies},
+    prelude::{
+        Address, AssetId, Contract, Error, LoadConfiguration, Provider, StorageConfiguration,
+        TxPolicies,
+    },
+    programs::call_response::FuelCallResponse,
     test_helpers::{launch_custom_provider_and_get_wallets, WalletsConfig},
-    types::{bech32::Bech32ContractId, Bytes, Identity},
+    types::{bech32::Bech32ContractId, Bytes, ContractId, Identity},
 };
 
 pub struct MultisigCaller {
@@ -17,6 +22,16 @@ pub struct CounterCaller {
     pub contract: Counter<WalletUnlocked>,
     pub wallet: WalletUnlocked,
 }
+
+pub struct ReverterCaller {
+    pub contract: Reverter<WalletUnlocked>,
+    pub wallet: WalletUnlocked,
+}
+
+pub struct ModuleCaller {
+    pub contract: Module<WalletUnlocked>,
+    pub wallet: WalletUnlocked,
+}
 pub fn base_asset_contract_id() -> AssetId {
     AssetId::BASE
 }
@@ -45,7 +60,19 @@ pub fn call_parameters() -> TransactionParameters {
     })
 }
 
-pub fn call_parameters_change_threshold(threshold: u8) -> TransactionParameters {
+pub fn call_parameters_always_revert(code: u64) -> TransactionParameters {
+    TransactionParameters::Call(ContractCallParams {
+        calldata: Bytes(calldata!(code).unwrap()),
+        forwarded_gas: DEFAULT_FORWARDED_GAS,
+        function_selector: Bytes(encode_fn_selector("always_revert")),
+        transfer_params: TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: None,
+        },
+    })
+}
+
+pub fn call_parameters_change_threshold(threshold: u64) -> TransactionParameters {
     TransactionParameters::Call(ContractCallParams {
         calldata: Bytes(calldata!(threshold).unwrap()),
         forwarded_gas: DEFAULT_FORWARDED_GAS,
@@ -57,9 +84,9 @@ pub fn call_parameters_change_threshold(threshold: u8) -> TransactionParameters
     })
 }
 
-pub fn call_parameters_add_owner(owner: Identity) -> TransactionParameters {
+pub fn call_parameters_add_owner(owner: Identity, weight: u64) -> TransactionParameters {
     TransactionParameters::Call(ContractCallParams {
-        calldata: Bytes(calldata!(owner).unwrap()),
+        calldata: Bytes(calldata!(owner, weight).unwrap()),
         forwarded_gas: DEFAULT_FORWARDED_GAS,
         function_selector: Bytes(encode_fn_selector("add_owner")),
         transfer_params: TransferParams {
@@ -69,6 +96,71 @@ pub fn call_parameters_add_owner(owner: Identity) -> TransactionParameters {
     })
 }
 
+pub fn call_parameters_set_owner_weight(owner: Identity, weight: u64) -> TransactionParameters {
+    TransactionParameters::Call(ContractCallParams {
+        calldata: Bytes(calldata!(owner, weight).unwrap()),
+        forwarded_gas: DEFAULT_FORWARDED_GAS,
+        function_selector: Bytes(encode_fn_selector("set_owner_weight")),
+        transfer_params: TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: None,
+        },
+    })
+}
+
+pub fn call_parameters_set_roles(identity: Identity, roles: Roles) -> TransactionParameters {
+    TransactionParameters::Call(ContractCallParams {
+        calldata: Bytes(calldata!(identity, roles).unwrap()),
+        forwarded_gas: DEFAULT_FORWARDED_GAS,
+        function_selector: Bytes(encode_fn_selector("set_roles")),
+        transfer_params: TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: None,
+        },
+    })
+}
+
+pub fn call_parameters_set_executor_policy(policy: ExecutorPolicy) -> TransactionParameters {
+    TransactionParameters::Call(ContractCallParams {
+        calldata: Bytes(calldata!(policy).unwrap()),
+        forwarded_gas: DEFAULT_FORWARDED_GAS,
+        function_selector: Bytes(encode_fn_selector("set_executor_policy")),
+        transfer_params: TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: None,
+        },
+    })
+}
+
+pub fn call_parameters_set_allowance(
+    delegate: Identity,
+    asset_id: AssetId,
+    amount: u64,
+    reset_period: u64,
+) -> TransactionParameters {
+    TransactionParameters::Call(ContractCallParams {
+        calldata: Bytes(calldata!(delegate, asset_id, amount, reset_period).unwrap()),
+        forwarded_gas: DEFAULT_FORWARDED_GAS,
+        function_selector: Bytes(encode_fn_selector("set_allowance")),
+        transfer_params: TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: None,
+        },
+    })
+}
+
+pub fn call_parameters_set_execution_delay(delay: u64) -> TransactionParameters {
+    TransactionParameters::Call(ContractCallParams {
+        calldata: Bytes(calldata!(delay).unwrap()),
+        forwarded_gas: DEFAULT_FORWARDED_GAS,
+        function_selector: Bytes(encode_fn_selector("set_execution_delay")),
+        transfer_params: TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: None,
+        },
+    })
+}
+
 pub fn call_parameters_remove_owner(owner: Identity) -> TransactionParameters {
     TransactionParameters::Call(ContractCallParams {
         calldata: Bytes(calldata!(owner).unwrap()),
@@ -81,6 +173,142 @@ pub fn call_parameters_remove_owner(owner: Identity) -> TransactionParameters {
     })
 }
 
+pub fn call_parameters_update_config(
+    add: Vec<Owner>,
+    remove: Vec<Identity>,
+    new_threshold: Option<u64>,
+) -> TransactionParameters {
+    TransactionParameters::Call(ContractCallParams {
+        calldata: Bytes(calldata!(add, remove, new_threshold).unwrap()),
+        forwarded_gas: DEFAULT_FORWARDED_GAS,
+        function_selector: Bytes(encode_fn_selector("update_config")),
+        transfer_params: TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: None,
+        },
+    })
+}
+
+pub fn call_parameters_swap_owner(
+    old_owner: Identity,
+    new_owner: Identity,
+) -> TransactionParameters {
+    TransactionParameters::Call(ContractCallParams {
+        calldata: Bytes(calldata!(old_owner, new_owner).unwrap()),
+        forwarded_gas: DEFAULT_FORWARDED_GAS,
+        function_selector: Bytes(encode_fn_selector("swap_owner")),
+        transfer_params: TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: None,
+        },
+    })
+}
+
+pub fn call_parameters_enable_module(module: ContractId) -> TransactionParameters {
+    TransactionParameters::Call(ContractCallParams {
+        calldata: Bytes(calldata!(module).unwrap()),
+        forwarded_gas: DEFAULT_FORWARDED_GAS,
+        function_selector: Bytes(encode_fn_selector("enable_module")),
+        transfer_params: TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: None,
+        },
+    })
+}
+
+pub fn call_parameters_disable_module(module: ContractId) -> TransactionParameters {
+    TransactionParameters::Call(ContractCallParams {
+        calldata: Bytes(calldata!(module).unwrap()),
+        forwarded_gas: DEFAULT_FORWARDED_GAS,
+        function_selector: Bytes(encode_fn_selector("disable_module")),
+        transfer_params: TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: None,
+        },
+    })
+}
+
+pub fn call_parameters_set_guard(guard: Option<ContractId>) -> TransactionParameters {
+    TransactionParameters::Call(ContractCallParams {
+        calldata: Bytes(calldata!(guard).unwrap()),
+        forwarded_gas: DEFAULT_FORWARDED_GAS,
+        function_selector: Bytes(encode_fn_selector("set_guard")),
+        transfer_params: TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: None,
+        },
+    })
+}
+
+pub fn call_parameters_set_guardians(
+    guardians: Vec<Identity>,
+    threshold: u64,
+    recovery_delay: u64,
+) -> TransactionParameters {
+    TransactionParameters::Call(ContractCallParams {
+        calldata: Bytes(calldata!(guardians, threshold, recovery_delay).unwrap()),
+        forwarded_gas: DEFAULT_FORWARDED_GAS,
+        function_selector: Bytes(encode_fn_selector("set_guardians")),
+        transfer_params: TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: None,
+        },
+    })
+}
+
+pub fn call_parameters_set_inactivity_switch(
+    beneficiary: Option<Identity>,
+    inactivity_period: u64,
+    assets: Vec<AssetId>,
+) -> TransactionParameters {
+    TransactionParameters::Call(ContractCallParams {
+        calldata: Bytes(calldata!(beneficiary, inactivity_period, assets).unwrap()),
+        forwarded_gas: DEFAULT_FORWARDED_GAS,
+        function_selector: Bytes(encode_fn_selector("set_inactivity_switch")),
+        transfer_params: TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: None,
+        },
+    })
+}
+
+pub fn call_parameters_set_proxy_target(new_target: ContractId) -> TransactionParameters {
+    TransactionParameters::Call(ContractCallParams {
+        calldata: Bytes(calldata!(new_target).unwrap()),
+        forwarded_gas: DEFAULT_FORWARDED_GAS,
+        function_selector: Bytes(encode_fn_selector("set_proxy_target")),
+        transfer_params: TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: None,
+        },
+    })
+}
+
+pub fn transfer_operation(to: Identity, value: u64) -> BatchOperation {
+    BatchOperation {
+        to,
+        parameters: OperationParameters::Transfer(TransferParams {
+            asset_id: base_asset_contract_id(),
+            value: Some(value),
+        }),
+    }
+}
+
+pub fn call_operation(counter_contract_id: &Bech32ContractId) -> BatchOperation {
+    BatchOperation {
+        to: Identity::ContractId(counter_contract_id.into()),
+        parameters: OperationParameters::Call(ContractCallParams {
+            calldata: Bytes(calldata!(5u64).unwrap()),
+            forwarded_gas: DEFAULT_FORWARDED_GAS,
+            function_selector: Bytes(encode_fn_selector("increment_counter")),
+            transfer_params: TransferParams {
+                asset_id: base_asset_contract_id(),
+                value: None,
+            },
+        }),
+    }
+}
+
 pub async fn get_wallets(num_wallets: u64) -> Vec<WalletUnlocked> {
     launch_custom_provider_and_get_wallets(
         WalletsConfig::new(
@@ -95,20 +323,43 @@ pub async fn get_wallets(num_wallets: u64) -> Vec<WalletUnlocked> {
     .unwrap()
 }
 
-pub fn wallets_to_identities(wallets: Vec<WalletUnlocked>) -> Vec<Identity> {
+/// Produces a new block `seconds` after the latest one.
+pub async fn advance_block_time(provider: &Provider, seconds: i64) {
+    let latest_block_time = provider.latest_block_time().await.unwrap().unwrap();
+    provider
+        .produce_blocks(1, Some(latest_block_time + Duration::seconds(seconds)))
+        .await
+        .unwrap();
+}
+
+pub fn wallets_to_owners(wallets: Vec<WalletUnlocked>) -> Vec<Owner> {
+    wallets_to_weighted_owners(wallets, 1)
+}
+
+pub fn wallets_to_weighted_owners(wallets: Vec<WalletUnlocked>, weight: u64) -> Vec<Owner> {
     wallets
         .iter()
-        .map(|wallet| Identity::Address(Address::from(wallet.address())))
+        .map(|wallet| Owner {
+            identity: Identity::Address(Address::from(wallet.address())),
+            weight,
+        })
         .collect()
 }
 
 pub async fn deploy_multisig(
     deployer: &WalletUnlocked,
+) -> Result<(Bech32ContractId, MultisigCaller), Error> {
+    deploy_multisig_with_configurables(deployer, MultisigConfigurables::default()).await
+}
+
+pub async fn deploy_multisig_with_configurables(
+    deployer: &WalletUnlocked,
+    configurables: MultisigConfigurables,
 ) -> Result<(Bech32ContractId, MultisigCaller), Error> {
     // Deploy the contract
     let multisig_contract_id = Contract::load_from(
         "../multisig-contract/out/debug/fuel-multisig.bin",
-        LoadConfiguration::default(),
+        LoadConfiguration::default().with_configurables(configurables),
     )
     .unwrap()
     .deploy(deployer, TxPolicies::default())
@@ -124,6 +375,51 @@ pub async fn deploy_multisig(
     Ok((multisig_contract_id, deployer))
 }
 
+pub async fn deploy_multisig_with_proxy(
+    deployer: &WalletUnlocked,
+) -> Result<(Bech32ContractId, Bech32ContractId, MultisigCaller), Error> {
+    // Deploy the multisig implementation
+    let (target_contract_id, _) = deploy_multisig(deployer).await.unwrap();
+
+    // Deploy the proxy pointing to the implementation
+    let proxy_contract_id = deploy_proxy(deployer, &target_contract_id).await.unwrap();
+
+    // Create a caller instance that calls the multisig through the proxy
+    let deployer = get_multisig_caller(&proxy_contract_id, deployer.clone());
+
+    Ok((proxy_contract_id, target_contract_id, deployer))
+}
+
+pub async fn deploy_proxy(
+    deployer: &WalletUnlocked,
+    target_contract_id: &Bech32ContractId,
+) -> Result<Bech32ContractId, Error> {
+    let configurables = MultisigProxyConfigurables::default()
+        .with_INITIAL_TARGET(ContractId::from(target_contract_id))
+        .unwrap();
+
+    // Initialize the multisig storage in the proxy, as the implementation runs with the proxy storage
+    let storage_configuration = StorageConfiguration::default()
+        .add_slot_overrides_from_file(
+            "../multisig-contract/out/debug/fuel-multisig-storage_slots.json",
+        )
+        .unwrap();
+
+    // Deploy the contract
+    let proxy_contract_id = Contract::load_from(
+        "../multisig-proxy/out/debug/multisig-proxy.bin",
+        LoadConfiguration::default()
+            .with_storage_configuration(storage_configuration)
+            .with_configurables(configurables),
+    )
+    .unwrap()
+    .deploy(deployer, TxPolicies::default())
+    .await
+    .unwrap();
+
+    Ok(proxy_contract_id)
+}
+
 pub async fn deploy_counter(
     deployer: &WalletUnlocked,
 ) -> Result<(Bech32ContractId, CounterCaller), Error> {
@@ -146,6 +442,67 @@ pub async fn deploy_counter(
     Ok((counter_contract_id, deployer))
 }
 
+pub async fn deploy_reverter(
+    deployer: &WalletUnlocked,
+) -> Result<(Bech32ContractId, ReverterCaller), Error> {
+    // Deploy the contract
+    let reverter_contract_id = Contract::load_from(
+        "./utils/test-contracts/reverter/out/debug/reverter.bin",
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .deploy(deployer, TxPolicies::default())
+    .await
+    .unwrap();
+
+    // Create a caller instance
+    let deployer = ReverterCaller {
+        contract: Reverter::new(reverter_contract_id.clone(), deployer.clone()),
+        wallet: deployer.clone(),
+    };
+
+    Ok((reverter_contract_id, deployer))
+}
+
+pub async fn deploy_module(
+    deployer: &WalletUnlocked,
+) -> Result<(Bech32ContractId, ModuleCaller), Error> {
+    // Deploy the contract
+    let module_contract_id = Contract::load_from(
+        "./utils/test-contracts/module/out/debug/module.bin",
+        LoadConfiguration::default(),
+    )
+    .unwrap()
+    .deploy(deployer, TxPolicies::default())
+    .await
+    .unwrap();
+
+    // Create a caller instance
+    let deployer = ModuleCaller {
+        contract: Module::new(module_contract_id.clone(), deployer.clone()),
+        wallet: deployer.clone(),
+    };
+
+    Ok((module_contract_id, deployer))
+}
+
+pub async fn deploy_guard(
+    deployer: &WalletUnlocked,
+    configurables: GuardConfigurables,
+) -> Result<Bech32ContractId, Error> {
+    // Deploy the contract
+    let guard_contract_id = Contract::load_from(
+        "./utils/test-contracts/guard/out/debug/guard.bin",
+        LoadConfiguration::default().with_configurables(configurables),
+    )
+    .unwrap()
+    .deploy(deployer, TxPolicies::default())
+    .await
+    .unwrap();
+
+    Ok(guard_contract_id)
+}
+
 pub fn get_multisig_caller(
     contract_id: &Bech32ContractId,
     wallet: WalletUnlocked,
@@ -155,3 +512,34 @@ pub fn get_multisig_caller(
         wallet,
     }
 }
+
+/// Proposes and executes a call to the multisig itself, which requires a threshold of 1.
+pub async fn execute_self_call(
+    deployer: &MultisigCaller,
+    contract_id: &Bech32ContractId,
+    transaction_parameters: TransactionParameters,
+) -> FuelCallResponse<()> {
+    // Propose the self call
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.into()),
+            3600,
+            transaction_parameters,
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Execute the tx because the threshold is 1
+    deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(contract_id.clone())
+        .call()
+        .await
+        .unwrap()
+}
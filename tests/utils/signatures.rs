@@ -0,0 +1,116 @@
+use super::abi::*;
+use fuels::{
+    accounts::wallet::WalletUnlocked,
+    core::traits::Signer,
+    crypto::{Hasher, Message},
+    types::{bech32::Bech32ContractId, Bits256, Bytes32, ContractId, Identity, B512, U256},
+};
+
+// Mirrors the `Hash` implementations of the multisig contract types.
+fn hash_u256(hasher: &mut Hasher, value: U256) {
+    let mut bytes = [0u8; 32];
+    value.to_big_endian(&mut bytes);
+    hasher.input(bytes);
+}
+
+fn hash_identity(hasher: &mut Hasher, identity: &Identity) {
+    match identity {
+        Identity::Address(address) => {
+            hasher.input([0u8]);
+            hasher.input(address);
+        }
+        Identity::ContractId(contract_id) => {
+            hasher.input([1u8]);
+            hasher.input(contract_id);
+        }
+    }
+}
+
+fn hash_transfer_params(hasher: &mut Hasher, transfer_params: &TransferParams) {
+    hasher.input(transfer_params.asset_id);
+    match transfer_params.value {
+        Some(value) => {
+            hasher.input([1u8]);
+            hasher.input(value.to_be_bytes());
+        }
+        None => hasher.input([0u8]),
+    }
+}
+
+fn hash_contract_call_params(hasher: &mut Hasher, contract_call_params: &ContractCallParams) {
+    hasher.input((contract_call_params.calldata.0.len() as u64).to_be_bytes());
+    hasher.input(&contract_call_params.calldata.0);
+    hasher.input(contract_call_params.forwarded_gas.to_be_bytes());
+    hasher.input((contract_call_params.function_selector.0.len() as u64).to_be_bytes());
+    hasher.input(&contract_call_params.function_selector.0);
+    hash_transfer_params(hasher, &contract_call_params.transfer_params);
+}
+
+fn hash_transaction_parameters(hasher: &mut Hasher, tx_parameters: &TransactionParameters) {
+    match tx_parameters {
+        TransactionParameters::Call(contract_call_params) => {
+            hasher.input([0u8]);
+            hash_contract_call_params(hasher, contract_call_params);
+        }
+        TransactionParameters::Transfer(transfer_params) => {
+            hasher.input([1u8]);
+            hash_transfer_params(hasher, transfer_params);
+        }
+        TransactionParameters::Batch(operations) => {
+            hasher.input([2u8]);
+            hasher.input((operations.len() as u64).to_be_bytes());
+            for operation in operations {
+                hash_identity(hasher, &operation.to);
+                match &operation.parameters {
+                    OperationParameters::Call(contract_call_params) => {
+                        hasher.input([0u8]);
+                        hash_contract_call_params(hasher, contract_call_params);
+                    }
+                    OperationParameters::Transfer(transfer_params) => {
+                        hasher.input([1u8]);
+                        hash_transfer_params(hasher, transfer_params);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Computes the same proposal hash as the multisig contract, given its current config epoch and
+/// the signature nonce of the transaction.
+pub fn compute_tx_hash(
+    multisig_contract_id: &Bech32ContractId,
+    tx: &TransactionData,
+    config_epoch: u64,
+    signature_nonce: u64,
+) -> Bytes32 {
+    let mut hasher = Hasher::default();
+    hasher.input(ContractId::from(multisig_contract_id));
+    hash_u256(&mut hasher, tx.tx_id);
+    hasher.input(config_epoch.to_be_bytes());
+    hasher.input(signature_nonce.to_be_bytes());
+    hash_identity(&mut hasher, &tx.to);
+    hasher.input(tx.valid_until.to_be_bytes());
+    match &tx.gas_refund {
+        Some(gas_refund) => {
+            hasher.input([1u8]);
+            hasher.input(gas_refund.asset_id);
+            hasher.input(gas_refund.gas_price.to_be_bytes());
+            hasher.input(gas_refund.max_amount.to_be_bytes());
+        }
+        None => hasher.input([0u8]),
+    }
+    hash_transaction_parameters(&mut hasher, &tx.tx_parameters);
+    hasher.finalize()
+}
+
+/// Signs a proposal hash with the given wallet.
+pub async fn sign_tx_hash(wallet: &WalletUnlocked, tx_hash: Bytes32) -> B512 {
+    let signature = wallet.sign(Message::from_bytes(*tx_hash)).await.unwrap();
+    let bytes: [u8; 64] = *signature;
+
+    B512::from((
+        Bits256(bytes[..32].try_into().unwrap()),
+        Bits256(bytes[32..].try_into().unwrap()),
+    ))
+}
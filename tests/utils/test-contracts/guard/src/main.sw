@@ -0,0 +1,54 @@
+contract;
+
+mod types;
+
+use types::*;
+use std::context::balance_of;
+
+configurable {
+    /// The contract that the multisig can never call.
+    BLOCKED_CONTRACT: ContractId = ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    /// The asset whose balance is checked after the execution.
+    MIN_BALANCE_ASSET: AssetId = AssetId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    /// The minimum balance of the asset the multisig must keep after the execution.
+    MIN_BALANCE: u64 = 0,
+}
+
+abi Guard {
+    fn check_transaction(tx: TransactionData, executor: Identity) -> bool;
+    fn check_after_execution(tx: TransactionData) -> bool;
+}
+
+impl Guard for Contract {
+    fn check_transaction(tx: TransactionData, _executor: Identity) -> bool {
+        match tx.tx_parameters {
+            TransactionParameters::Batch(operations) => {
+                let mut i = 0;
+                while i < operations.len() {
+                    if is_blocked(operations.get(i).unwrap().to) {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            },
+            _ => !is_blocked(tx.to),
+        }
+    }
+
+    fn check_after_execution(_tx: TransactionData) -> bool {
+        let multisig = match msg_sender() {
+            Ok(Identity::ContractId(multisig)) => multisig,
+            _ => revert(0),
+        };
+
+        balance_of(multisig, MIN_BALANCE_ASSET) >= MIN_BALANCE
+    }
+}
+
+fn is_blocked(to: Identity) -> bool {
+    match to {
+        Identity::ContractId(contract_id) => contract_id == BLOCKED_CONTRACT,
+        _ => false,
+    }
+}
@@ -0,0 +1,54 @@
+library;
+
+use std::bytes::Bytes;
+
+// Copies of the multisig types received by the guard. They must match the multisig contract types.
+pub type TxId = u256;
+pub type Approvals = u64;
+pub type Rejections = u64;
+
+pub struct TransferParams {
+    pub asset_id: AssetId,
+    pub value: Option<u64>,
+}
+
+pub struct ContractCallParams {
+    pub calldata: Bytes,
+    pub forwarded_gas: u64,
+    pub function_selector: Bytes,
+    pub transfer_params: TransferParams,
+}
+
+pub enum OperationParameters {
+    Call: ContractCallParams,
+    Transfer: TransferParams,
+}
+
+pub struct BatchOperation {
+    pub to: Identity,
+    pub parameters: OperationParameters,
+}
+
+pub enum TransactionParameters {
+    Call: ContractCallParams,
+    Transfer: TransferParams,
+    Batch: Vec<BatchOperation>,
+}
+
+pub struct GasRefund {
+    pub asset_id: AssetId,
+    pub gas_price: u64,
+    pub max_amount: u64,
+}
+
+pub struct TransactionData {
+    pub tx_id: TxId,
+    pub proposer: Identity,
+    pub to: Identity,
+    pub valid_until: u64,
+    pub config_epoch: u64,
+    pub gas_refund: Option<GasRefund>,
+    pub tx_parameters: TransactionParameters,
+    pub approvals_count: Approvals,
+    pub rejections_count: Rejections,
+}
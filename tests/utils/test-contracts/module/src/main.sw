@@ -0,0 +1,27 @@
+contract;
+
+mod types;
+
+use types::*;
+
+abi Multisig {
+    #[storage(read)]
+    fn exec_from_module(to: Identity, tx_parameters: TransactionParameters);
+}
+
+abi TestModule {
+    fn transfer_from_multisig(multisig: ContractId, to: Identity, asset_id: AssetId, amount: u64);
+}
+
+impl TestModule for Contract {
+    fn transfer_from_multisig(multisig: ContractId, to: Identity, asset_id: AssetId, amount: u64) {
+        let multisig = abi(Multisig, multisig.bits());
+        multisig.exec_from_module(
+            to,
+            TransactionParameters::Transfer(TransferParams {
+                asset_id,
+                value: Some(amount),
+            }),
+        );
+    }
+}
@@ -0,0 +1,32 @@
+library;
+
+use std::bytes::Bytes;
+
+// Copies of the multisig types used by `exec_from_module`. They must match the multisig contract types.
+pub struct TransferParams {
+    pub asset_id: AssetId,
+    pub value: Option<u64>,
+}
+
+pub struct ContractCallParams {
+    pub calldata: Bytes,
+    pub forwarded_gas: u64,
+    pub function_selector: Bytes,
+    pub transfer_params: TransferParams,
+}
+
+pub enum OperationParameters {
+    Call: ContractCallParams,
+    Transfer: TransferParams,
+}
+
+pub struct BatchOperation {
+    pub to: Identity,
+    pub parameters: OperationParameters,
+}
+
+pub enum TransactionParameters {
+    Call: ContractCallParams,
+    Transfer: TransferParams,
+    Batch: Vec<BatchOperation>,
+}
@@ -0,0 +1,11 @@
+contract;
+
+abi TestContract {
+    fn always_revert(code: u64);
+}
+
+impl TestContract for Contract {
+    fn always_revert(code: u64) {
+        revert(code);
+    }
+}
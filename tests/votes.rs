@@ -0,0 +1,189 @@
+use fuels::prelude::*;
+
+use crate::utils::setup::{
+    deploy_multisig, get_multisig_caller, get_wallets, transfer_parameters, wallets_to_owners,
+};
+use crate::utils::validate_error;
+
+#[tokio::test]
+async fn given_a_multisig_with_a_proposed_transaction_when_the_proposer_revokes_its_approval_then_the_transaction_can_not_be_executed(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer, which is approved by the proposer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Revoke the approval of the proposer
+    let response = deployer.contract.methods().revoke_vote(tx_id).call().await;
+
+    assert!(response.is_ok());
+
+    // Check the vote was removed
+    let tx = deployer
+        .contract
+        .methods()
+        .get_tx(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+    let approval = deployer
+        .contract
+        .methods()
+        .get_tx_approval_by_owner(tx_id, owners_list[0].identity.clone())
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(tx.approvals_count, 0);
+    assert_eq!(approval, None);
+
+    // Try to execute the tx
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "ThresholdNotReached");
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_an_approved_transaction_when_the_owner_changes_its_vote_then_the_approval_becomes_a_rejection(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+    let threshold = 2;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // A second owner approves the transfer by mistake
+    let caller = get_multisig_caller(&contract_id, wallets[1].clone());
+    let _ = caller
+        .contract
+        .methods()
+        .approve_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // The second owner flips its approval to a rejection
+    let response = caller.contract.methods().change_vote(tx_id).call().await;
+
+    assert!(response.is_ok());
+
+    // Check the counts and the vote
+    let tx = deployer
+        .contract
+        .methods()
+        .get_tx(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+    let approval = deployer
+        .contract
+        .methods()
+        .get_tx_approval_by_owner(tx_id, owners_list[1].identity.clone())
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(tx.approvals_count, 1);
+    assert_eq!(tx.rejections_count, 1);
+    assert_eq!(approval, Some(false));
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_a_proposed_transaction_when_an_owner_that_has_not_voted_revokes_then_should_throw_not_voted(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 2;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Propose a transfer
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Try to revoke from an owner that has not voted
+    let caller = get_multisig_caller(&contract_id, wallets[1].clone());
+    let response = caller.contract.methods().revoke_vote(tx_id).call().await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "NotVoted");
+}
@@ -0,0 +1,367 @@
+use fuels::prelude::*;
+use fuels::types::{bech32::Bech32ContractId, Identity};
+
+use crate::utils::abi::Owner;
+use crate::utils::constants::DEFAULT_TRANSFER_AMOUNT;
+use crate::utils::setup::{
+    call_parameters_set_owner_weight, deploy_multisig, get_multisig_caller, get_wallets,
+    transfer_parameters, wallets_to_owners, wallets_to_weighted_owners, MultisigCaller,
+};
+use crate::utils::validate_error;
+
+async fn set_owner_weight(
+    deployer: &MultisigCaller,
+    approver: &MultisigCaller,
+    contract_id: &Bech32ContractId,
+    owner: Identity,
+    weight: u64,
+) {
+    // Propose the weight change
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.into()),
+            3600,
+            call_parameters_set_owner_weight(owner, weight),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Approve the tx to reach the threshold of 2
+    approver
+        .contract
+        .methods()
+        .approve_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Execute the tx
+    deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(contract_id.clone())
+        .call()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn given_a_multisig_with_a_founder_of_weight_two_and_threshold_three_when_one_more_owner_approves_then_the_transaction_is_executed(
+) {
+    let wallets = get_wallets(3).await;
+    let mut owners_list = wallets_to_weighted_owners(wallets[0..1].to_vec(), 2);
+    owners_list.extend(wallets_to_owners(wallets[1..3].to_vec()));
+    let threshold = 3;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Check the owners and their weights
+    let owners = deployer
+        .contract
+        .methods()
+        .get_owners()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let total_weight = deployer
+        .contract
+        .methods()
+        .get_total_weight()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(owners, owners_list);
+    assert_eq!(total_weight, 4);
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            deployer.contract.contract_id(),
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    // The founder proposes a transfer, which counts as an approval of weight 2
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // The threshold is not reached yet
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    assert!(response.is_err());
+    validate_error(response, "ThresholdNotReached");
+
+    // One more owner approves
+    let caller = get_multisig_caller(&contract_id, wallets[1].clone());
+    let _ = caller
+        .contract
+        .methods()
+        .approve_tx(tx_id)
+        .call()
+        .await
+        .unwrap();
+
+    let tx = deployer
+        .contract
+        .methods()
+        .get_tx(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+
+    assert_eq!(tx.approvals_count, 3);
+
+    // Execute the tx after the threshold is reached
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn given_a_multisig_when_proposing_to_change_the_weight_of_an_owner_then_the_weight_and_total_weight_are_updated(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_owners(wallets[0..2].to_vec());
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    let owner = owners_list[1].identity.clone();
+
+    // Propose to change the weight of the second owner
+    let tx_id = deployer
+        .contract
+        .methods()
+        .propose_tx(
+            Identity::ContractId(contract_id.clone().into()),
+            3600,
+            call_parameters_set_owner_weight(owner.clone(), 5),
+        )
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Execute the tx because the threshold is 1
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_contract(contract_id)
+        .call()
+        .await;
+
+    assert!(response.is_ok());
+
+    // Check the new weights
+    let owners = deployer
+        .contract
+        .methods()
+        .get_owners()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+    let total_weight = deployer
+        .contract
+        .methods()
+        .get_total_weight()
+        .simulate()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(
+        owners[1],
+        Owner {
+            identity: owner,
+            weight: 5
+        }
+    );
+    assert_eq!(total_weight, 6);
+}
+
+#[tokio::test]
+async fn given_a_multisig_not_initialized_when_an_owner_has_weight_zero_then_should_throw_weight_cannot_be_zero(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_weighted_owners(wallets[0..2].to_vec(), 0);
+    let threshold = 1;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Try to call the constructor with owners of weight 0
+    let response = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "WeightCannotBeZero");
+}
+
+#[tokio::test]
+async fn given_a_multisig_not_initialized_when_the_threshold_is_greater_than_the_total_weight_then_should_throw_threshold_cannot_be_greater_than_owners(
+) {
+    let wallets = get_wallets(2).await;
+    let owners_list = wallets_to_weighted_owners(wallets[0..2].to_vec(), 2);
+    let threshold = 5;
+
+    // Deploy the multisig contract
+    let (_, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Try to call the constructor with a threshold greater than the total weight
+    let response = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list)
+        .call()
+        .await;
+
+    // Check if the tx reverted
+    assert!(response.is_err());
+
+    // Check the error
+    validate_error(response, "ThresholdCannotBeGreaterThanOwners");
+}
+
+#[tokio::test]
+async fn given_a_tx_approved_by_an_owner_when_its_weight_is_lowered_then_its_approval_counts_with_the_new_weight(
+) {
+    let wallets = get_wallets(3).await;
+    let owners_list = wallets_to_owners(wallets[0..3].to_vec());
+    let threshold = 2;
+
+    // Deploy the multisig contract
+    let (contract_id, deployer) = deploy_multisig(&wallets[0]).await.unwrap();
+
+    // Call the multisig constructor
+    let _ = deployer
+        .contract
+        .methods()
+        .constructor(threshold, owners_list.clone())
+        .call()
+        .await
+        .unwrap();
+
+    // Transfer some funds to the contract
+    deployer
+        .wallet
+        .force_transfer_to_contract(
+            &contract_id,
+            DEFAULT_TRANSFER_AMOUNT,
+            AssetId::BASE,
+            TxPolicies::default(),
+        )
+        .await
+        .unwrap();
+
+    let owner = owners_list[1].identity.clone();
+    let owner_caller = get_multisig_caller(&contract_id, wallets[1].clone());
+    let third_owner_caller = get_multisig_caller(&contract_id, wallets[2].clone());
+
+    // The second owner proposes a transfer with a weight that reaches the threshold alone
+    set_owner_weight(
+        &deployer,
+        &third_owner_caller,
+        &contract_id,
+        owner.clone(),
+        2,
+    )
+    .await;
+    let (_, receiver, transaction_parameters) = transfer_parameters();
+    let tx_id = owner_caller
+        .contract
+        .methods()
+        .propose_tx(receiver, 3600, transaction_parameters)
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    // Lower the weight of the second owner after its approval
+    set_owner_weight(&deployer, &third_owner_caller, &contract_id, owner, 1).await;
+
+    // Check that the approval counts with the new weight
+    let tx = deployer
+        .contract
+        .methods()
+        .get_tx(tx_id)
+        .simulate()
+        .await
+        .unwrap()
+        .value
+        .unwrap();
+
+    assert_eq!(tx.approvals_count, 1);
+
+    // Try to execute the transfer
+    let response = deployer
+        .contract
+        .methods()
+        .execute_tx(tx_id)
+        .append_variable_outputs(1)
+        .call()
+        .await;
+
+    validate_error(response, "ThresholdNotReached");
+}